@@ -0,0 +1,197 @@
+//! Generates the module list of the aggregate `solutions` binary from the solutions present in `src/bin`
+//! and in the archived years below `years`, and the example tests of every solution from its manifest in `data/examples`.
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use tinyjson::JsonValue;
+
+fn main() {
+    println!("cargo::rerun-if-changed=src/bin");
+    println!("cargo::rerun-if-changed=data/examples");
+    println!("cargo::rerun-if-changed=years");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+    let days = find_days(&bin_dir);
+
+    let mut modules = String::new();
+    let mut registrations = String::new();
+
+    for day in &days {
+        modules.push_str(&module(&bin_dir, day, &format!("day_{day}")));
+        registrations.push_str(&format!(
+            "        \x20   day_{day}::register(&mut registry);\n"
+        ));
+    }
+
+    let mut archived_registrations = String::new();
+
+    for (year, year_bin_dir) in find_archived_years(&Path::new(&manifest_dir).join("years")) {
+        archived_registrations.push_str(&format!("        Some({year}) => {{\n"));
+
+        for day in find_days(&year_bin_dir) {
+            let name = format!("year_{year}_day_{day}");
+            modules.push_str(&module(&year_bin_dir, &day, &name));
+            archived_registrations
+                .push_str(&format!("            {name}::register(&mut registry);\n"));
+        }
+
+        archived_registrations.push_str("        }\n");
+    }
+
+    // NOTE: the active year is registered by default, archived years only if they were selected with `--year`.
+    let source = format!(
+        "{modules}\n\
+        fn registry() -> advent_of_code::template::registry::Registry {{\n\
+        \x20   #[allow(unused_mut)]\n\
+        \x20   let mut registry = advent_of_code::template::registry::Registry::new();\n\
+        \x20   let archived = advent_of_code::template::Year::selected()\n\
+        \x20       .filter(|year| !year.is_active())\n\
+        \x20       .map(|year| year.into_inner());\n\
+        \x20   match archived {{\n\
+        {archived_registrations}\
+        \x20       Some(_) => {{}}\n\
+        \x20       None => {{\n\
+        {registrations}\
+        \x20       }}\n\
+        \x20   }}\n\
+        \x20   registry\n\
+        }}\n"
+    );
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), source).unwrap();
+
+    let examples_dir = Path::new(&manifest_dir).join("data").join("examples");
+
+    for day in &days {
+        let manifest = fs::read_to_string(examples_dir.join(format!("{day}.json"))).ok();
+        let tests = manifest.map(|m| example_tests(day, &m)).unwrap_or_default();
+        fs::write(
+            Path::new(&out_dir).join(format!("examples_{day}.rs")),
+            tests,
+        )
+        .unwrap();
+    }
+
+    // the `solutions` binary includes every day as a module, but does not run their examples.
+    fs::write(Path::new(&out_dir).join("examples_solutions.rs"), "").unwrap();
+}
+
+/// The days that have a solution in `bin_dir`, sorted.
+fn find_days(bin_dir: &Path) -> Vec<String> {
+    let mut days: Vec<String> = fs::read_dir(bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter_map(|name| {
+                    let day = name.strip_suffix(".rs")?;
+                    let valid = day.len() == 2
+                        && day.parse::<u8>().is_ok_and(|day| (1..=25).contains(&day));
+                    valid.then(|| day.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort_unstable();
+    days
+}
+
+/// The archived years in `years_dir` with the directory of their solutions, sorted.
+fn find_archived_years(years_dir: &Path) -> Vec<(u16, PathBuf)> {
+    let mut years: Vec<(u16, PathBuf)> = fs::read_dir(years_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let entry = entry.ok()?;
+                    let year = entry.file_name().into_string().ok()?.parse().ok()?;
+                    Some((year, entry.path().join("src").join("bin")))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    years.sort_unstable();
+    years
+}
+
+fn module(bin_dir: &Path, day: &str, name: &str) -> String {
+    let path = bin_dir.join(format!("{day}.rs"));
+    format!(
+        "#[allow(dead_code)]\n#[path = {:?}]\nmod {name};\n",
+        path.display().to_string()
+    )
+}
+
+/// One test per entry of a day's examples manifest, named after its part and name, e.g. `part_one_larger`.
+/// The entries are parsed and checked at runtime by `template::manifest`, only names and parts are read here.
+fn example_tests(day: &str, manifest: &str) -> String {
+    let check = |name: &str, index: usize| {
+        format!(
+            "#[test]\n\
+            fn {name}() {{\n\
+            \x20   advent_of_code::template::manifest::check_example(super::register, super::DAY, {index});\n\
+            }}\n"
+        )
+    };
+
+    let entries = match JsonValue::from_str(manifest) {
+        Ok(JsonValue::Array(entries)) => entries,
+        // surface the error in a test, rather than failing the build of every binary.
+        _ => {
+            return "#[test]\n\
+                fn manifest() {\n\
+                \x20   advent_of_code::template::manifest::Manifest::read(super::DAY).unwrap();\n\
+                }\n"
+            .into();
+        }
+    };
+
+    let mut names: Vec<String> = vec![];
+    let mut tests = String::new();
+
+    for (index, entry) in entries.iter().enumerate() {
+        let entry = entry.get::<HashMap<String, JsonValue>>();
+        let field = |key: &str| entry.and_then(|entry| entry.get(key));
+
+        let part = match field("part") {
+            Some(JsonValue::Number(part)) if *part == 2.0 => "part_two",
+            _ => "part_one",
+        };
+
+        let name = match (field("name"), field("file")) {
+            (Some(JsonValue::String(name)), _) => name.clone(),
+            (_, Some(JsonValue::String(file))) => file
+                .rsplit_once('.')
+                .map_or(&**file, |(stem, _)| stem)
+                .into(),
+            _ => day.to_string(),
+        };
+
+        let name: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        let mut name = format!("{part}_{name}");
+        if names.contains(&name) {
+            name = format!("{name}_{}", index + 1);
+        }
+
+        tests.push_str(&check(&name, index));
+        names.push(name);
+    }
+
+    tests
+}
@@ -1,4 +1,6 @@
-advent_of_code::solution!(1);
+advent_of_code::solution!(1, Dial);
+
+use advent_of_code::template::Solution;
 
 #[inline(always)]
 fn parse_num(bytes: &[u8]) -> i64 {
@@ -9,68 +11,98 @@ fn parse_num(bytes: &[u8]) -> i64 {
     n
 }
 
-fn solve(input: &str) -> (u64, u64) {
-    let mut position: i64 = 50;
-    let mut count1: u64 = 0;
-    let mut count2: u64 = 0;
+/// A single rotation of the dial: whether it turns left, and by how many clicks.
+pub struct Rotation {
+    is_left: bool,
+    distance: i64,
+}
 
-    for line in input.as_bytes().split(|&b| b == b'\n') {
-        if line.is_empty() {
-            continue;
-        }
+fn parse_rotations(input: &str) -> Vec<Rotation> {
+    input
+        .as_bytes()
+        .split(|&b| b == b'\n')
+        .filter(|line| !line.is_empty())
+        .map(|line| Rotation {
+            is_left: line[0] == b'L',
+            distance: parse_num(&line[1..]),
+        })
+        .collect()
+}
+
+fn turn(position: i64, rotation: &Rotation) -> i64 {
+    if rotation.is_left {
+        (position - rotation.distance).rem_euclid(100)
+    } else {
+        (position + rotation.distance).rem_euclid(100)
+    }
+}
 
-        let is_left = line[0] == b'L';
-        let distance = parse_num(&line[1..]);
+pub struct Dial;
 
-        // Part 2: count all times we pass through 0
-        let first = if is_left {
-            if position == 0 { 100 } else { position }
-        } else {
-            if position == 0 { 100 } else { 100 - position }
-        };
+impl Solution for Dial {
+    type Parsed<'a> = Vec<Rotation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-        if first <= distance {
-            count2 += ((distance - first) / 100 + 1) as u64;
-        }
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_rotations(input)
+    }
 
-        // Update position
-        if is_left {
-            position -= distance;
-        } else {
-            position += distance;
-        }
-        position = position.rem_euclid(100);
+    fn part_one(rotations: &Self::Parsed<'_>) -> Option<u64> {
+        let mut position: i64 = 50;
+        let mut count: u64 = 0;
 
-        // Part 1: count only when ending at 0
-        if position == 0 {
-            count1 += 1;
+        for rotation in rotations {
+            position = turn(position, rotation);
+
+            // Count only when ending at 0
+            if position == 0 {
+                count += 1;
+            }
         }
+
+        Some(count)
     }
 
-    (count1, count2)
-}
+    fn part_two(rotations: &Self::Parsed<'_>) -> Option<u64> {
+        let mut position: i64 = 50;
+        let mut count: u64 = 0;
 
-pub fn part_one(input: &str) -> Option<u64> {
-    Some(solve(input).0)
-}
+        for rotation in rotations {
+            // Count all times we pass through 0
+            let first = if position == 0 {
+                100
+            } else if rotation.is_left {
+                position
+            } else {
+                100 - position
+            };
+
+            if first <= rotation.distance {
+                count += ((rotation.distance - first) / 100 + 1) as u64;
+            }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    Some(solve(input).1)
+            position = turn(position, rotation);
+        }
+
+        Some(count)
+    }
 }
 
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = Dial::solve_part_one(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = Dial::solve_part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(6));
     }
 }
@@ -30,7 +30,7 @@ fn find_invalid_ids_in_range_p1(start: u64, end: u64) -> u64 {
 // Check if a k-digit pattern is "primitive" (not a repetition of a shorter pattern)
 fn is_primitive(pattern: u64, k: u32) -> bool {
     for d in 1..k {
-        if k % d != 0 {
+        if !k.is_multiple_of(d) {
             continue;
         }
         // Check if pattern == (first d digits) repeated k/d times
@@ -1,7 +1,8 @@
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, Inventory);
 
-fn parse_and_merge(input: &str) -> Vec<(u64, u64)> {
-    let ranges_str = input.split("\n\n").next().unwrap();
+use advent_of_code::template::Solution;
+
+fn parse_and_merge(ranges_str: &str) -> Vec<(u64, u64)> {
     let mut ranges: Vec<(u64, u64)> = ranges_str
         .lines()
         .map(|line| {
@@ -36,20 +37,39 @@ fn is_fresh(merged: &[(u64, u64)], id: u64) -> bool {
         .is_ok()
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let merged = parse_and_merge(input);
-    let ids_str = input.split("\n\n").nth(1)?;
-
-    let count = ids_str
-        .lines()
-        .filter(|line| is_fresh(&merged, line.parse().unwrap()))
-        .count();
+pub struct Inventory;
 
-    Some(count as u64)
+pub struct Database<'a> {
+    merged: Vec<(u64, u64)>,
+    ids_str: Option<&'a str>,
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    Some(parse_and_merge(input).iter().map(|(s, e)| e - s + 1).sum())
+impl Solution for Inventory {
+    type Parsed<'a> = Database<'a>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let mut sections = input.split("\n\n");
+        Database {
+            merged: parse_and_merge(sections.next().unwrap()),
+            ids_str: sections.next(),
+        }
+    }
+
+    fn part_one(db: &Self::Parsed<'_>) -> Option<u64> {
+        let count = db
+            .ids_str?
+            .lines()
+            .filter(|line| is_fresh(&db.merged, line.parse().unwrap()))
+            .count();
+
+        Some(count as u64)
+    }
+
+    fn part_two(db: &Self::Parsed<'_>) -> Option<u64> {
+        Some(db.merged.iter().map(|(s, e)| e - s + 1).sum())
+    }
 }
 
 #[cfg(test)]
@@ -58,13 +78,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = Inventory::solve_part_one(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = Inventory::solve_part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(14));
     }
 }
@@ -1,6 +1,37 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(7, Manifold);
 
-pub fn part_one(input: &str) -> Option<u64> {
+use advent_of_code::template::Solution;
+
+pub struct Manifold;
+
+pub struct Diagram<'a> {
+    lines: Vec<&'a [u8]>,
+    rows: usize,
+    cols: usize,
+    start_r: usize,
+    start_c: usize,
+}
+
+impl Solution for Manifold {
+    /// `None` if the diagram is empty or has no start position.
+    type Parsed<'a> = Option<Diagram<'a>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part_one(diagram: &Self::Parsed<'_>) -> Option<u64> {
+        count_splitters(diagram.as_ref()?)
+    }
+
+    fn part_two(diagram: &Self::Parsed<'_>) -> Option<u64> {
+        count_timelines(diagram.as_ref()?)
+    }
+}
+
+fn parse(input: &str) -> Option<Diagram<'_>> {
     let lines: Vec<&[u8]> = input.lines().map(|l| l.as_bytes()).collect();
     if lines.is_empty() {
         return None;
@@ -29,19 +60,37 @@ pub fn part_one(input: &str) -> Option<u64> {
         return None;
     }
 
+    Some(Diagram {
+        lines,
+        rows,
+        cols,
+        start_r,
+        start_c,
+    })
+}
+
+fn count_splitters(diagram: &Diagram) -> Option<u64> {
+    let &Diagram {
+        ref lines,
+        rows,
+        cols,
+        start_r,
+        start_c,
+    } = diagram;
+
     let mut current_beams = vec![false; cols];
     current_beams[start_c] = true;
     let mut next_beams = vec![false; cols];
 
     let mut unique_splitters = 0;
 
-    for r in start_r..rows {
+    for line in &lines[start_r..rows] {
         // Clear next_beams
         next_beams.fill(false);
         
         for c in 0..cols {
             if current_beams[c] {
-                let byte = lines[r][c];
+                let byte = line[c];
                 match byte {
                     b'^' => {
                         unique_splitters += 1;
@@ -72,34 +121,14 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(unique_splitters)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let lines: Vec<&[u8]> = input.lines().map(|l| l.as_bytes()).collect();
-    if lines.is_empty() {
-        return None;
-    }
-    let rows = lines.len();
-    let cols = lines[0].len();
-
-    let mut start_c = 0;
-    let mut start_r = 0;
-    let mut found = false;
-    for (r, line) in lines.iter().enumerate() {
-        for (c, &b) in line.iter().enumerate() {
-            if b == b'S' {
-                start_r = r;
-                start_c = c;
-                found = true;
-                break;
-            }
-        }
-        if found {
-            break;
-        }
-    }
-
-    if !found {
-        return None;
-    }
+fn count_timelines(diagram: &Diagram) -> Option<u64> {
+    let &Diagram {
+        ref lines,
+        rows,
+        cols,
+        start_r,
+        start_c,
+    } = diagram;
 
     let mut current_counts = vec![0u64; cols];
     current_counts[start_c] = 1;
@@ -107,7 +136,7 @@ pub fn part_two(input: &str) -> Option<u64> {
 
     let mut total_timelines = 0;
 
-    for r in start_r..rows {
+    for line in &lines[start_r..rows] {
         next_counts.fill(0);
         
         for c in 0..cols {
@@ -116,7 +145,7 @@ pub fn part_two(input: &str) -> Option<u64> {
                 continue;
             }
 
-            let byte = lines[r][c];
+            let byte = line[c];
             match byte {
                 b'^' => {
                     // Left
@@ -157,13 +186,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = Manifold::solve_part_one(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = Manifold::solve_part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(40));
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(8);
+advent_of_code::solution!(8, generator = generate);
 
 #[derive(Clone, Copy)]
 struct Point {
@@ -163,11 +163,9 @@ pub fn part_two(input: &str) -> Option<u64> {
     let mut last_edge_idx = 0;
 
     for (i, edge) in edges.iter().enumerate() {
-        if dsu.union(edge.a, edge.b) {
-            if dsu.components == 1 {
-                last_edge_idx = i;
-                break;
-            }
+        if dsu.union(edge.a, edge.b) && dsu.components == 1 {
+            last_edge_idx = i;
+            break;
         }
     }
 
@@ -176,6 +174,21 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(product as u64)
 }
 
+/// Generates `size` random junction boxes, used by `cargo scale`.
+pub fn generate(size: usize) -> String {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % 100_000
+    };
+
+    (0..size)
+        .map(|_| format!("{},{},{}\n", next(), next(), next()))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,4 @@
-advent_of_code::solution!(9);
+advent_of_code::solution!(9, generator = generate);
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 struct Point {
@@ -161,6 +161,36 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(max_area)
 }
 
+/// Generates a staircase-shaped loop of roughly `size` red tiles, used by `cargo scale`.
+/// Every step adds two corners, the remaining corners close the loop along the axes.
+pub fn generate(size: usize) -> String {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % 1000 + 1
+    };
+
+    let steps = (size / 2).saturating_sub(1).max(1);
+    let mut points = vec![(0, 0)];
+    let (mut x, mut y) = (0, 0);
+
+    for _ in 0..steps {
+        x += next();
+        points.push((x, y));
+        y += next();
+        points.push((x, y));
+    }
+
+    points.push((0, y));
+
+    points
+        .iter()
+        .map(|(x, y)| format!("{x},{y}\n"))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,7 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, countdown, download, examples, read, readme, rollover, scaffold, scale, solve, status,
+    test_day, time, timings, verify,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
@@ -7,8 +10,10 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        Day, InputSource, Limits, RunOptions, ScaleOptions, Thresholds, Year,
+    };
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
         Download {
@@ -17,65 +22,181 @@ mod args {
         Read {
             day: Day,
         },
+        Readme,
+        ExamplesExtract {
+            day: Day,
+            overwrite: bool,
+        },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
-            submit: Option<u8>,
+            options: RunOptions,
         },
         All {
             release: bool,
+            limits: Limits,
+            jobs: usize,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            report: bool,
+            limits: Limits,
+            compare: Option<Thresholds>,
+            jobs: usize,
+        },
+        Scale {
+            day: Day,
+            part: Option<u8>,
+            options: ScaleOptions,
+        },
+        TestDay {
+            day: Day,
+            bless: bool,
+        },
+        Rollover {
+            next: Option<Year>,
+        },
+        Status {
+            skip_examples: bool,
+        },
+        Verify {
+            days: Vec<Day>,
+            limits: Limits,
+            jobs: usize,
+        },
+        TimingsHistory {
+            day: Day,
+            sparkline: bool,
         },
         #[cfg(feature = "today")]
         Today,
+        Countdown,
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        // every command operates on the year passed with `--year`, or on the active year.
+        if let Some(year) = args.opt_value_from_str("--year")? {
+            Year::select(year);
+        }
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                limits: parse_limits(&mut args)?,
+                jobs: parse_jobs(&mut args)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let report = args.contains("--report");
+                let limits = parse_limits(&mut args)?;
+                let compare = parse_thresholds(&mut args)?;
+                // NOTE: timings are only comparable if days do not compete for resources, stay serial by default.
+                let jobs = parse_jobs(&mut args)?;
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    report,
+                    limits,
+                    compare,
+                    jobs,
                 }
             }
+            Some("scale") => AppArguments::Scale {
+                part: args.opt_value_from_str("--part")?,
+                options: ScaleOptions::from_args(&mut args)?,
+                day: args.free_from_str()?,
+            },
+            Some("verify") => {
+                let limits = parse_limits(&mut args)?;
+                let jobs = parse_jobs(&mut args)?;
+                let mut days = vec![];
+                while let Some(day) = args.opt_free_from_str()? {
+                    days.push(day);
+                }
+
+                AppArguments::Verify { days, limits, jobs }
+            }
+            Some("readme") => AppArguments::Readme,
+            Some("rollover") => AppArguments::Rollover {
+                next: args.opt_free_from_str()?,
+            },
+            Some("status") => AppArguments::Status {
+                skip_examples: args.contains("--skip-examples"),
+            },
+            Some("test-day") => AppArguments::TestDay {
+                bless: args.contains("--bless"),
+                day: args.free_from_str()?,
+            },
+            Some("timings") => match args.subcommand()?.as_deref() {
+                Some("history") => AppArguments::TimingsHistory {
+                    sparkline: args.contains("--sparkline"),
+                    day: args.free_from_str()?,
+                },
+                _ => {
+                    eprintln!("Unknown timings command, expected `history`.");
+                    process::exit(1);
+                }
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
+            Some("examples") => match args.subcommand()?.as_deref() {
+                Some("extract") => AppArguments::ExamplesExtract {
+                    overwrite: args.contains("--overwrite"),
+                    day: args.free_from_str()?,
+                },
+                _ => {
+                    eprintln!("Unknown examples command, expected `extract`.");
+                    process::exit(1);
+                }
+            },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let day = args.free_from_str()?;
+                let release = args.contains("--release");
+                let dhat = args.contains("--dhat");
+                let mut options = RunOptions::from_args(&mut args)?;
+
+                // an input path (or `-` for stdin) may follow the day.
+                if options.input.is_none() {
+                    let input: Option<String> = args.opt_free_from_str()?;
+                    options.input = input.map(|input| InputSource::parse(&input));
+                }
+
+                AppArguments::Solve {
+                    day,
+                    release,
+                    dhat,
+                    options,
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
+            Some("countdown") => AppArguments::Countdown,
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -93,6 +214,42 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Parse `--time-limit <seconds>` and `--memory-limit <megabytes>`.
+    fn parse_limits(args: &mut pico_args::Arguments) -> Result<Limits, pico_args::Error> {
+        let time: Option<f64> = args.opt_value_from_str("--time-limit")?;
+        let memory: Option<u64> = args.opt_value_from_str("--memory-limit")?;
+
+        Ok(Limits {
+            time: time.map(Duration::from_secs_f64),
+            memory: memory.map(|mb| mb * 1024 * 1024),
+        })
+    }
+
+    /// Parse `--jobs <N>`, the number of days to run concurrently. Defaults to running days one by one.
+    fn parse_jobs(args: &mut pico_args::Arguments) -> Result<usize, pico_args::Error> {
+        let jobs: Option<usize> = args.opt_value_from_str("--jobs")?;
+        Ok(jobs.unwrap_or(1).max(1))
+    }
+
+    /// Parse `--compare` with optional `--noise <percent>` and `--max-regression <percent>`.
+    fn parse_thresholds(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Thresholds>, pico_args::Error> {
+        let compare = args.contains("--compare");
+        let noise: Option<f64> = args.opt_value_from_str("--noise")?;
+        let max_regression: Option<f64> = args.opt_value_from_str("--max-regression")?;
+
+        if !compare {
+            return Ok(None);
+        }
+
+        let defaults = Thresholds::default();
+        Ok(Some(Thresholds {
+            noise: noise.unwrap_or(defaults.noise),
+            max_regression: max_regression.unwrap_or(defaults.max_regression),
+        }))
+    }
 }
 
 fn main() {
@@ -102,38 +259,65 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::All {
+                release,
+                limits,
+                jobs,
+            } => all::handle(release, limits, jobs),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                report,
+                limits,
+                compare,
+                jobs,
+            } => time::handle(day, all, store, report, limits, compare, jobs),
+            AppArguments::Scale { day, part, options } => scale::handle(day, part, options),
+            AppArguments::Verify { days, limits, jobs } => verify::handle(days, limits, jobs),
+            AppArguments::TestDay { day, bless } => test_day::handle(day, bless),
+            AppArguments::Rollover { next } => rollover::handle(next),
+            AppArguments::Status { skip_examples } => status::handle(skip_examples),
+            AppArguments::TimingsHistory { day, sparkline } => {
+                timings::handle_history(day, sparkline);
+            }
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
+            AppArguments::Readme => readme::handle(),
+            AppArguments::ExamplesExtract { day, overwrite } => {
+                examples::handle_extract(day, overwrite);
+            }
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
+                // download first, so that the template can use the puzzle's title and examples.
                 if download {
                     download::handle(day);
                 }
+                scaffold::handle(day, overwrite, template.as_deref());
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
+                options,
+            } => solve::handle(day, release, dhat, &options),
+            AppArguments::Countdown => countdown::handle(),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
                         download::handle(day);
+                        scaffold::handle(day, false, None);
                         read::handle(day)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run while the event runs. \
+                            Please use `scaffold` with a specific day, or `countdown` to wait for the next one."
                         );
                         process::exit(1)
                     }
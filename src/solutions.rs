@@ -0,0 +1,14 @@
+//! Aggregate binary that links every solution in `src/bin` and runs a set of days in-process.
+//! Used by `cargo all` and `cargo time` to avoid invoking cargo once per day.
+//! Solutions of archived years are linked as well, and run with `--year <year>`.
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+fn main() {
+    if let Err(e) = advent_of_code::template::Year::select_from_args() {
+        eprintln!("Invalid year: {e}");
+        std::process::exit(1);
+    }
+
+    registry().run_from_args();
+}
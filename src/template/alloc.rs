@@ -0,0 +1,150 @@
+//! A global allocator that counts allocations, so that every run can report its memory usage.
+//!
+//! It wraps the system allocator and is installed by the library, which makes it available to every
+//! solution without further setup. When the `dhat-heap` feature is enabled, dhat's allocator is used instead.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+pub struct CountingAlloc;
+
+/// Allocation statistics of a single run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total number of bytes requested.
+    pub bytes: u64,
+    /// Highest number of bytes that were allocated at the same time, relative to the start of the run.
+    pub peak_bytes: u64,
+}
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+// SAFETY: all calls are forwarded to the system allocator unchanged.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Run `func` and count the allocations it makes.
+/// Returns [`None`] for the statistics if the counting allocator is not installed.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if cfg!(feature = "dhat-heap") {
+        return (func(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let live = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_LIVE_BYTES.store(live, Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+        peak_bytes: PEAK_LIVE_BYTES.load(Ordering::Relaxed).saturating_sub(live) as u64,
+    };
+
+    (result, Some(stats))
+}
+
+/// Format a number of bytes with a binary unit, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+impl AllocStats {
+    /// Short summary for terminal and README output, e.g. `3 allocs, 1.5 KiB, peak 1.0 KiB`.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} allocs, {}, peak {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, measure};
+    use std::hint::black_box;
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn counts_allocations() {
+        let (capacity, stats) = measure(|| {
+            let a: Vec<u8> = black_box(Vec::with_capacity(4096));
+            let b: Vec<u8> = black_box(Vec::with_capacity(1024));
+            a.capacity() + b.capacity()
+        });
+
+        let stats = stats.unwrap();
+        assert_eq!(capacity, 5120);
+        // NOTE: tests run concurrently and share the allocator, so only lower bounds can be checked.
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 5120);
+    }
+}
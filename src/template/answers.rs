@@ -0,0 +1,257 @@
+//! Known answers of solved days, stored in `data/answers.json`.
+//!
+//! Answers are keyed by day, or by `day/input` for named inputs:
+//!
+//! ```json
+//! { "01": { "part_1": "142", "part_2": "281" }, "01/alice": { "part_1": "157" } }
+//! ```
+//!
+//! The runner marks every result against these answers, `cargo verify` fails on any mismatch.
+//! Answers that were accepted on submission are added automatically.
+
+use std::{collections::HashMap, fs, io, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::inputs::DEFAULT_INPUT_NAME;
+use crate::template::record::PartRecord;
+use crate::template::{Day, year_path};
+
+/// The answers file of the selected year.
+pub fn answers_file_path() -> String {
+    year_path("data/answers.json")
+}
+
+/// The result of checking an answer against the known answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    /// Holds the expected answer.
+    Wrong(String),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    data: HashMap<String, [Option<String>; 2]>,
+}
+
+impl Answers {
+    /// Read the answers file. If it is not present, no answers are known.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(answers_file_path()) {
+            Ok(content) => Answers::from_str(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn store_file(&self) -> Result<(), io::Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(answers_file_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// The known answer of a part. `input` is the label of a named input, the default input has no label.
+    pub fn get(&self, day: Day, part: u8, input: Option<&str>) -> Option<&str> {
+        let index = usize::from(part).checked_sub(1)?;
+        self.data.get(&key(day, input))?.get(index)?.as_deref()
+    }
+
+    pub fn set(&mut self, day: Day, part: u8, input: Option<&str>, answer: &str) {
+        if let Some(index) = usize::from(part).checked_sub(1).filter(|i| *i < 2) {
+            self.data.entry(key(day, input)).or_default()[index] = Some(answer.to_string());
+        }
+    }
+
+    /// Check the answer of a record. Returns [`None`] if there is no known answer for the part.
+    pub fn check(&self, record: &PartRecord) -> Option<Check> {
+        let expected = self.get(record.day, record.part, record.input.as_deref())?;
+
+        if record.answer.as_deref() == Some(expected) {
+            Some(Check::Correct)
+        } else {
+            Some(Check::Wrong(expected.to_string()))
+        }
+    }
+
+    /// The known answers of a day as `(input, part, answer)`, sorted by input and part.
+    pub fn for_day(&self, day: Day) -> Vec<(Option<&str>, u8, &str)> {
+        let mut answers: Vec<(Option<&str>, u8, &str)> = self
+            .data
+            .iter()
+            .filter_map(|(key, parts)| {
+                let (key_day, input) = match key.split_once('/') {
+                    Some((key_day, input)) => (key_day, Some(input)),
+                    None => (key.as_str(), None),
+                };
+
+                (Day::from_str(key_day).ok()? == day).then_some((input, parts))
+            })
+            .flat_map(|(input, parts)| {
+                (1..=2)
+                    .zip(parts)
+                    .filter_map(move |(part, answer)| Some((input, part, answer.as_deref()?)))
+            })
+            .collect();
+
+        answers.sort_unstable();
+        answers
+    }
+
+    /// All days that have at least one known answer, sorted.
+    pub fn days(&self) -> Vec<Day> {
+        let mut days: Vec<Day> = self
+            .data
+            .keys()
+            .filter_map(|key| Day::from_str(key.split('/').next()?).ok())
+            .collect();
+
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+}
+
+fn key(day: Day, input: Option<&str>) -> String {
+    match input.filter(|input| *input != DEFAULT_INPUT_NAME) {
+        Some(input) => format!("{day}/{input}"),
+        None => day.to_string(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).map_err(|e| e.to_string())?;
+        Answers::try_from(&json)
+    }
+}
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let data = value
+            .data
+            .iter()
+            .map(|(key, parts)| {
+                let parts: HashMap<String, JsonValue> = parts
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, answer)| {
+                        Some((
+                            format!("part_{}", i + 1),
+                            JsonValue::String(answer.clone()?),
+                        ))
+                    })
+                    .collect();
+
+                (key.clone(), JsonValue::Object(parts))
+            })
+            .collect();
+
+        JsonValue::Object(data)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answers {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answers to be a JSON object.")?;
+
+        let mut data = HashMap::new();
+
+        for (key, parts) in json {
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or(format!("Expected answers.{key} to be an object."))?;
+
+            // answers are compared as strings, but numbers are accepted for convenience.
+            let answer = |part: &str| match parts.get(part) {
+                Some(JsonValue::String(s)) => Ok(Some(s.clone())),
+                Some(JsonValue::Number(n)) => Ok(Some(n.to_string())),
+                Some(JsonValue::Null) | None => Ok(None),
+                Some(_) => Err(format!(
+                    "Expected answers.{key}.{part} to be a string or number."
+                )),
+            };
+
+            data.insert(key.clone(), [answer("part_1")?, answer("part_2")?]);
+        }
+
+        Ok(Answers { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Answers, Check};
+    use crate::day;
+    use crate::template::{record::PartRecord, stats::BenchStats};
+
+    fn record(part: u8, answer: &str, input: Option<&str>) -> PartRecord {
+        PartRecord::new(
+            day!(1),
+            part,
+            Some(answer.into()),
+            BenchStats::single(Duration::ZERO),
+        )
+        .with_input(input.map(String::from))
+    }
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::from_str(
+            r#"{ "01": { "part_1": "142", "part_2": 281 }, "01/alice": { "part_1": "157" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(day!(1), 1, None), Some("142"));
+        assert_eq!(answers.get(day!(1), 2, Some("default")), Some("281"));
+        assert_eq!(answers.get(day!(1), 1, Some("alice")), Some("157"));
+        assert_eq!(answers.get(day!(1), 2, Some("alice")), None);
+        assert_eq!(answers.days(), vec![day!(1)]);
+        assert_eq!(
+            answers.for_day(day!(1)),
+            vec![
+                (None, 1, "142"),
+                (None, 2, "281"),
+                (Some("alice"), 1, "157")
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_records() {
+        let answers = Answers::from_str(r#"{ "01": { "part_1": "142" } }"#).unwrap();
+
+        assert_eq!(answers.check(&record(1, "142", None)), Some(Check::Correct));
+        assert_eq!(
+            answers.check(&record(1, "143", None)),
+            Some(Check::Wrong("142".into()))
+        );
+        assert_eq!(answers.check(&record(2, "1", None)), None);
+        assert_eq!(answers.check(&record(1, "142", Some("bob"))), None);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let answers =
+            Answers::from_str(r#"{ "03/bob": { "part_2": "ABC" }, "04": { "part_1": "7" } }"#)
+                .unwrap();
+
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::from_str(&json).unwrap(), answers);
+    }
+}
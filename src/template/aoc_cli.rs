@@ -1,10 +1,13 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
+    env,
     fmt::Display,
+    fs, process,
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::backend::{AocBackend, AocError, get_year};
+use crate::template::{Day, SubmitOutcome};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -16,7 +19,10 @@ pub enum AocCommandError {
 impl Display for AocCommandError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AocCommandError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
+            AocCommandError::CommandNotFound => write!(
+                f,
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            ),
             AocCommandError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
@@ -25,6 +31,27 @@ impl Display for AocCommandError {
     }
 }
 
+/// Backend that shells out to an installed aoc-cli.
+pub struct AocCli;
+
+impl AocBackend for AocCli {
+    fn check(&self) -> Result<(), AocError> {
+        Ok(check()?)
+    }
+
+    fn input(&self, day: Day) -> Result<String, AocError> {
+        download_to_file(day, "--input-only", "--input-file")
+    }
+
+    fn puzzle(&self, day: Day) -> Result<String, AocError> {
+        download_to_file(day, "--puzzle-only", "--puzzle-file")
+    }
+
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<SubmitOutcome, AocError> {
+        Ok(SubmitOutcome::parse(&submit(day, part, answer)?))
+    }
+}
+
 pub fn check() -> Result<(), AocCommandError> {
     Command::new("aoc")
         .arg("-V")
@@ -33,66 +60,49 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+/// Submit an answer and return the response printed by aoc-cli.
+pub fn submit(day: Day, part: u8, result: &str) -> Result<String, AocCommandError> {
+    // workaround: the argument order is inverted for submit.
+    let mut args = build_args("submit", &[], day);
+    args.push(part.to_string());
+    args.push(result.to_string());
 
-    let args = build_args(
-        "read",
-        &[
-            "--description-only".into(),
-            "--puzzle-file".into(),
-            puzzle_path,
-        ],
-        day,
-    );
+    let output = Command::new("aoc")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
 
-    call_aoc_cli(&args)
+    let response = String::from_utf8_lossy(&output.stdout).to_string();
+
+    if output.status.success() {
+        Ok(response)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+/// Let aoc-cli download the input or the puzzle to a temporary file, and return its content.
+fn download_to_file(day: Day, only_flag: &str, file_flag: &str) -> Result<String, AocError> {
+    let path = env::temp_dir().join(format!("aoc-download-{}-{day}", process::id()));
 
     let args = build_args(
         "download",
         &[
             "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
-            "--puzzle-file".into(),
-            puzzle_path.to_string(),
+            only_flag.into(),
+            file_flag.into(),
+            path.display().to_string(),
         ],
         day,
     );
 
-    let output = call_aoc_cli(&args)?;
-    println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-    Ok(output)
-}
-
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
-    // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
-    args.push(part.to_string());
-    args.push(result.to_string());
-    call_aoc_cli(&args)
-}
+    call_aoc_cli(&args)?;
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
-
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
-}
-
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+    let content = fs::read_to_string(&path)?;
+    let _ = fs::remove_file(&path);
+    Ok(content)
 }
 
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
@@ -112,7 +122,7 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
+        .stdout(Stdio::null())
         .stderr(Stdio::inherit())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
@@ -0,0 +1,253 @@
+//! Built-in HTTP client for adventofcode.com.
+//!
+//! The session token is read from `AOC_SESSION`, or from the file at `AOC_SESSION_FILE`
+//! (default: `~/.adventofcode.session`, the same file aoc-cli uses).
+//! `AOC_BASE_URL` points the client at another server, e.g. a local mock.
+//! `AOC_CONTACT` is sent along with every request, so that the Advent of Code team can reach you.
+
+use std::{env, fs, path::PathBuf, time::Duration};
+
+use crate::template::backend::{AocBackend, AocError, get_year};
+use crate::template::html;
+use crate::template::{Day, SubmitOutcome};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable that holds a way to contact the owner of this repository, e.g. its URL or an email address.
+pub const CONTACT_ENV: &str = "AOC_CONTACT";
+
+/// Identifies the client to the Advent of Code servers, as requested by their maintainer.
+/// The contact is taken from `AOC_CONTACT`, or from the `repository` of the package manifest.
+fn user_agent() -> String {
+    let contact = env::var(CONTACT_ENV)
+        .ok()
+        .filter(|contact| !contact.trim().is_empty())
+        .or_else(|| option_env!("CARGO_PKG_REPOSITORY").map(String::from))
+        .filter(|contact| !contact.is_empty());
+
+    let agent = concat!(env!("CARGO_PKG_NAME"), " v", env!("CARGO_PKG_VERSION"));
+    match contact {
+        Some(contact) => format!("{agent} ({})", contact.trim()),
+        None => agent.into(),
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: Option<String>,
+    year: Option<u16>,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: Option<String>, year: Option<u16>) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&user_agent())
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            year,
+            agent,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Self::new(&base_url, read_session(), get_year())
+    }
+
+    fn day_url(&self, day: Day) -> Result<String, AocError> {
+        let year = self.year.ok_or(AocError::MissingYear)?;
+        Ok(format!("{}/{year}/day/{}", self.base_url, day.into_inner()))
+    }
+
+    fn cookie(&self) -> Result<String, AocError> {
+        let session = self.session.as_ref().ok_or(AocError::MissingSession)?;
+        Ok(format!("session={session}"))
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &self.cookie()?)
+            .call()
+            .map_err(request_error)?;
+
+        response
+            .into_string()
+            .map_err(|e| AocError::Request(e.to_string()))
+    }
+}
+
+impl AocBackend for AocClient {
+    fn check(&self) -> Result<(), AocError> {
+        self.cookie()?;
+        self.year.ok_or(AocError::MissingYear)?;
+        Ok(())
+    }
+
+    fn input(&self, day: Day) -> Result<String, AocError> {
+        self.get(&format!("{}/input", self.day_url(day)?))
+    }
+
+    fn puzzle(&self, day: Day) -> Result<String, AocError> {
+        let page = self.get(&self.day_url(day)?)?;
+        Ok(html::articles_to_markdown(&page))
+    }
+
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<SubmitOutcome, AocError> {
+        let part = part.to_string();
+
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)?))
+            .set("Cookie", &self.cookie()?)
+            .send_form(&[("level", part.as_str()), ("answer", answer)])
+            .map_err(request_error)?;
+
+        let page = response
+            .into_string()
+            .map_err(|e| AocError::Request(e.to_string()))?;
+
+        Ok(SubmitOutcome::parse(&html::articles_to_markdown(&page)))
+    }
+}
+
+fn request_error(e: ureq::Error) -> AocError {
+    match e {
+        ureq::Error::Status(404, response) => AocError::Request(format!(
+            "{} was not found, is the puzzle unlocked yet?",
+            response.get_url()
+        )),
+        ureq::Error::Status(400 | 500, response) => AocError::Request(format!(
+            "{} answered with status {}, is the session token valid?",
+            response.get_url(),
+            response.status()
+        )),
+        e => AocError::Request(e.to_string()),
+    }
+}
+
+/// The session token, from `AOC_SESSION` or the session file.
+fn read_session() -> Option<String> {
+    let token = match env::var("AOC_SESSION") {
+        Ok(token) => token,
+        Err(_) => fs::read_to_string(session_file_path()?).ok()?,
+    };
+
+    let token = token.trim();
+    let token = token.strip_prefix("session=").unwrap_or(token);
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+/// The file the session token is read from, `AOC_SESSION_FILE` or `~/.adventofcode.session`.
+pub fn session_file_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var("AOC_SESSION_FILE") {
+        return Some(path.into());
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(".adventofcode.session"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::AocClient;
+    use crate::day;
+    use crate::template::{SubmitOutcome, backend::AocBackend};
+
+    /// Serve `count` requests on a local port. Every request is answered with `body` and sent back through the handle.
+    fn mock_server(count: usize, body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for stream in listener.incoming().take(count) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:")
+                    {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+
+            requests
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn downloads_inputs() {
+        let (url, server) = mock_server(1, "1\n2\n3\n");
+        let client = AocClient::new(&url, Some("abc".into()), Some(2025));
+
+        assert_eq!(client.input(day!(5)).unwrap(), "1\n2\n3\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2025/day/5/input HTTP/1.1"));
+        assert!(requests[0].contains("session=abc"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, server) = mock_server(
+            1,
+            "<main><article><p>That's the right answer!</p></article></main>",
+        );
+        let client = AocClient::new(&url, Some("abc".into()), Some(2025));
+
+        assert_eq!(
+            client.submit(day!(12), 2, "a b").unwrap(),
+            SubmitOutcome::Correct
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2025/day/12/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=a+b"));
+    }
+
+    #[test]
+    fn requires_a_session() {
+        let client = AocClient::new("http://127.0.0.1:1", None, Some(2025));
+        assert!(client.check().is_err());
+        assert!(client.input(day!(1)).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+//! Access to adventofcode.com: downloading inputs, reading puzzles and submitting answers.
+//!
+//! By default, the built-in HTTP client is used. Set `AOC_BACKEND=aoc-cli` to go through an installed aoc-cli instead.
+
+use std::{env, fmt::Display, fs, io, path::Path};
+
+use crate::template::aoc_cli::{AocCli, AocCommandError};
+use crate::template::aoc_client::{AocClient, session_file_path};
+use crate::template::{Day, SubmitOutcome, Year, year_path};
+
+/// Environment variable that selects the backend, either `native` or `aoc-cli`.
+pub const BACKEND_ENV: &str = "AOC_BACKEND";
+
+#[derive(Debug)]
+pub enum AocError {
+    Cli(AocCommandError),
+    /// No session token was found in the environment or the session file.
+    MissingSession,
+    /// No year was selected with `--year`, and `AOC_YEAR` is not set to a valid year.
+    MissingYear,
+    /// The request failed or adventofcode.com answered with an error.
+    Request(String),
+    Io(io::Error),
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Cli(e) => write!(f, "{e}"),
+            AocError::MissingSession => match session_file_path() {
+                Some(path) => write!(
+                    f,
+                    "no session token found. Set AOC_SESSION, or store the token in \"{}\" (AOC_SESSION_FILE selects another file).",
+                    path.display()
+                ),
+                None => write!(
+                    f,
+                    "no session token found. Set AOC_SESSION, or AOC_SESSION_FILE to the file the token is stored in."
+                ),
+            },
+            AocError::MissingYear => write!(f, "AOC_YEAR is not set to a valid year."),
+            AocError::Request(e) => write!(f, "request failed: {e}"),
+            AocError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<AocCommandError> for AocError {
+    fn from(e: AocCommandError) -> Self {
+        AocError::Cli(e)
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::Io(e)
+    }
+}
+
+pub trait AocBackend {
+    /// Check that the backend can be used, e.g. that credentials or the required binaries are present.
+    fn check(&self) -> Result<(), AocError>;
+
+    /// The puzzle input of a day.
+    fn input(&self, day: Day) -> Result<String, AocError>;
+
+    /// The description of a day's puzzle as markdown. Includes part two once it is unlocked.
+    fn puzzle(&self, day: Day) -> Result<String, AocError>;
+
+    /// Submit an answer and return the parsed response.
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<SubmitOutcome, AocError>;
+}
+
+/// The backend selected with [`BACKEND_ENV`].
+pub fn from_env() -> Box<dyn AocBackend> {
+    match env::var(BACKEND_ENV).as_deref() {
+        Ok("aoc-cli") => Box::new(AocCli),
+        Ok("native") | Err(_) => Box::new(AocClient::from_env()),
+        Ok(x) => {
+            eprintln!(
+                "Unknown backend `{x}`, expected `native` or `aoc-cli`. Using the native client."
+            );
+            Box::new(AocClient::from_env())
+        }
+    }
+}
+
+/// Write the input and puzzle description of a day to `data/inputs` and `data/puzzles`.
+pub fn download(backend: &dyn AocBackend, day: Day) -> Result<(), AocError> {
+    let input_path = get_input_path(day);
+    let puzzle_path = get_puzzle_path(day);
+
+    // archived years may not have all directories yet.
+    for path in [&input_path, &puzzle_path] {
+        if let Some(dir) = Path::new(path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+    }
+
+    fs::write(&input_path, backend.input(day)?)?;
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+
+    fs::write(&puzzle_path, backend.puzzle(day)?)?;
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+
+    Ok(())
+}
+
+/// Print the puzzle description of a day, and update it in `data/puzzles`.
+pub fn read(backend: &dyn AocBackend, day: Day) -> Result<(), AocError> {
+    let puzzle = backend.puzzle(day)?;
+    fs::write(get_puzzle_path(day), &puzzle)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+pub fn get_input_path(day: Day) -> String {
+    year_path(&format!("data/inputs/{day}.txt"))
+}
+
+pub fn get_puzzle_path(day: Day) -> String {
+    year_path(&format!("data/puzzles/{day}.md"))
+}
+
+/// The selected year, see [`Year::selected`].
+pub fn get_year() -> Option<u16> {
+    Year::selected().map(Year::into_inner)
+}
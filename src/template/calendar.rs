@@ -0,0 +1,221 @@
+//! The calendar of an event: how many puzzles it has, and when each of them unlocks.
+//!
+//! Puzzles unlock at midnight EST (UTC-5), one per day starting on the 1st of December.
+//! The events up to 2024 have 25 puzzles, the events from 2025 on have 12.
+//!
+//! The current time is read from a [`Clock`], so that tests can drive the calendar with a [`ManualClock`].
+
+use std::{
+    cell::Cell,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::{Day, Year};
+
+/// Offset of the puzzle server's timezone (EST) to UTC, in hours.
+const SERVER_UTC_OFFSET: i64 = -5;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+
+    fn sleep(&self, duration: Duration);
+}
+
+/// The system time.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// A clock that stands still unless it sleeps or is advanced.
+pub struct ManualClock {
+    now: Cell<SystemTime>,
+}
+
+impl ManualClock {
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        self.now.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calendar {
+    year: Year,
+}
+
+impl Calendar {
+    pub fn new(year: Year) -> Self {
+        Self { year }
+    }
+
+    /// The calendar of the selected year, see [`Year::selected`].
+    pub fn selected() -> Option<Self> {
+        Year::selected().map(Self::new)
+    }
+
+    pub fn year(self) -> Year {
+        self.year
+    }
+
+    /// The number of puzzles of the event.
+    pub fn days(self) -> u8 {
+        if self.year.into_inner() >= 2025 {
+            12
+        } else {
+            25
+        }
+    }
+
+    pub fn contains(self, day: Day) -> bool {
+        day.into_inner() <= self.days()
+    }
+
+    /// The instant a day's puzzle unlocks.
+    pub fn unlock(self, day: Day) -> SystemTime {
+        let date = days_from_civil(
+            i64::from(self.year.into_inner()),
+            12,
+            day.into_inner().into(),
+        );
+        let seconds = date * SECONDS_PER_DAY as i64 - SERVER_UTC_OFFSET * 3600;
+        UNIX_EPOCH + Duration::from_secs(seconds.max(0) as u64)
+    }
+
+    /// The day whose puzzle unlocked most recently, while the event runs. The puzzle of the last day
+    /// counts as today until the next midnight, just like every other one.
+    pub fn today(self, clock: &dyn Clock) -> Option<Day> {
+        let since_start = clock.now().duration_since(self.unlock(Day::new(1)?)).ok()?;
+        let day = u8::try_from(since_start.as_secs() / SECONDS_PER_DAY + 1).ok()?;
+        Day::new(day).filter(|day| self.contains(*day))
+    }
+
+    /// The next day whose puzzle is still locked, if any.
+    pub fn next_unlock(self, clock: &dyn Clock) -> Option<Day> {
+        let now = clock.now();
+        (1..=self.days())
+            .filter_map(Day::new)
+            .find(|day| self.unlock(*day) > now)
+    }
+}
+
+/// Wait until `instant`, calling `tick` with the remaining time about once per second.
+pub fn wait_until(clock: &dyn Clock, instant: SystemTime, mut tick: impl FnMut(Duration)) {
+    while let Ok(remaining) = instant.duration_since(clock.now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        tick(remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+}
+
+/// Days since the unix epoch of a date in the proleptic gregorian calendar.
+/// See <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{Calendar, Clock, ManualClock, wait_until};
+    use crate::day;
+    use crate::template::Year;
+
+    fn calendar(year: u16) -> Calendar {
+        Calendar::new(Year::new(year).unwrap())
+    }
+
+    #[test]
+    fn counts_days_per_event() {
+        assert_eq!(calendar(2015).days(), 25);
+        assert_eq!(calendar(2024).days(), 25);
+        assert_eq!(calendar(2025).days(), 12);
+        assert!(calendar(2025).contains(day!(12)));
+        assert!(!calendar(2025).contains(day!(13)));
+    }
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        // 2025-12-01T05:00:00Z
+        assert_eq!(
+            calendar(2025).unlock(day!(1)),
+            UNIX_EPOCH + Duration::from_secs(1_764_565_200)
+        );
+        // 2024-12-25T05:00:00Z
+        assert_eq!(
+            calendar(2024).unlock(day!(25)),
+            UNIX_EPOCH + Duration::from_secs(1_735_102_800)
+        );
+    }
+
+    #[test]
+    fn finds_today_and_next_unlock() {
+        let calendar = calendar(2025);
+        let clock = ManualClock::new(calendar.unlock(day!(1)) - Duration::from_secs(1));
+
+        assert_eq!(calendar.today(&clock), None);
+        assert_eq!(calendar.next_unlock(&clock), Some(day!(1)));
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(calendar.today(&clock), Some(day!(1)));
+        assert_eq!(calendar.next_unlock(&clock), Some(day!(2)));
+
+        clock.advance(Duration::from_secs(11 * 24 * 3600 + 3600));
+        assert_eq!(calendar.today(&clock), Some(day!(12)));
+        assert_eq!(calendar.next_unlock(&clock), None);
+
+        clock.advance(Duration::from_secs(24 * 3600));
+        assert_eq!(calendar.today(&clock), None);
+    }
+
+    #[test]
+    fn waits_until_an_instant() {
+        let clock = ManualClock::new(UNIX_EPOCH);
+        let mut ticks = vec![];
+
+        wait_until(
+            &clock,
+            UNIX_EPOCH + Duration::from_millis(2500),
+            |remaining| ticks.push(remaining.as_millis()),
+        );
+
+        assert_eq!(ticks, vec![2500, 1500, 500]);
+        assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_millis(2500));
+    }
+}
@@ -1,5 +1,6 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{Limits, all_days, limits::LimitsConfig, run_multi::run_multi};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(is_release: bool, limits: Limits, jobs: usize) {
+    let limits = LimitsConfig::read_from_file().with_default(limits);
+    run_multi(&all_days().collect(), is_release, false, &limits, jobs);
 }
@@ -0,0 +1,66 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    process,
+    time::Duration,
+};
+
+use crate::template::calendar::{self, Calendar, SystemClock};
+use crate::template::commands::{download, scaffold};
+use crate::template::submissions::format_wait;
+
+/// Grace period after an unlock, so that the puzzle is available on the server as well.
+const UNLOCK_MARGIN: Duration = Duration::from_secs(2);
+
+/// Wait until the next puzzle of the selected year unlocks, then download and scaffold it.
+pub fn handle() {
+    let Some(calendar) = Calendar::selected() else {
+        eprintln!("No year selected. Set AOC_YEAR or pass `--year`.");
+        process::exit(1);
+    };
+
+    let clock = SystemClock;
+
+    let Some(day) = calendar.next_unlock(&clock) else {
+        println!(
+            "All {} puzzles of {} are unlocked.",
+            calendar.days(),
+            calendar.year()
+        );
+        return;
+    };
+
+    let is_terminal = io::stdout().is_terminal();
+    let mut first = true;
+
+    calendar::wait_until(&clock, calendar.unlock(day) + UNLOCK_MARGIN, |remaining| {
+        // NOTE: redraw the line on terminals, print a line per minute elsewhere.
+        if is_terminal {
+            print!(
+                "\r\x1b[KDay {day} unlocks in {}",
+                format_remaining(remaining)
+            );
+            let _ = io::stdout().flush();
+        } else if first || remaining.as_secs() % 60 == 0 {
+            println!("Day {day} unlocks in {}", format_remaining(remaining));
+        }
+        first = false;
+    });
+
+    if is_terminal {
+        println!();
+    }
+
+    download::handle(day);
+    scaffold::handle(day, false, None);
+}
+
+fn format_remaining(remaining: Duration) -> String {
+    let hours = remaining.as_secs() / 3600;
+    let rest = format_wait(Duration::from_secs(remaining.as_secs() % 3600));
+
+    if hours > 0 {
+        format!("{hours}h {rest}")
+    } else {
+        rest
+    }
+}
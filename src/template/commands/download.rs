@@ -1,16 +1,16 @@
-use crate::template::{Day, aoc_cli};
+use crate::template::{Day, backend};
 use std::process;
 
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+    let backend = backend::from_env();
+
+    if let Err(e) = backend.check() {
+        eprintln!("{e}");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = backend::download(backend.as_ref(), day) {
+        eprintln!("failed to download day {day}: {e}");
         process::exit(1);
     };
 }
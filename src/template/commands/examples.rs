@@ -0,0 +1,182 @@
+use std::{
+    fs,
+    io::{self, IsTerminal, Write},
+    process,
+};
+
+use crate::template::backend::get_puzzle_path;
+use crate::template::examples::{self, PartCandidates};
+use crate::template::{Day, year_path};
+
+/// Extract the examples and expected answers of a day from its puzzle description.
+/// The examples are written to `data/examples`, the expected answers to the tests of the solution.
+pub fn handle_extract(day: Day, overwrite: bool) {
+    let puzzle_path = get_puzzle_path(day);
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!("Could not read \"{puzzle_path}\". Run `cargo download {day}` first.");
+        process::exit(1);
+    };
+
+    let parts = examples::parse_puzzle(&markdown);
+    let mut part_one_example: Option<String> = None;
+    // whether the example file of part one holds its extracted example.
+    let mut part_one_current = false;
+
+    for candidates in &parts {
+        let part = candidates.part;
+        let example = pick_block(candidates);
+
+        let example = match (example, part) {
+            (Some(example), _) => example,
+            // part two usually reuses the example of part one.
+            (None, 2) => match &part_one_example {
+                Some(example) => example.clone(),
+                None => continue,
+            },
+            (None, _) => {
+                println!("No example found for part {part}.");
+                continue;
+            }
+        };
+
+        let part_file = part == 2 && part_one_example.as_ref() != Some(&example);
+        let example_path = if part_file {
+            year_path(&format!("data/examples/{day}-{part}.txt"))
+        } else {
+            year_path(&format!("data/examples/{day}.txt"))
+        };
+
+        let current = if part == 1 || part_file {
+            write_example(&example_path, &example, overwrite)
+        } else {
+            part_one_current
+        };
+
+        if part == 1 {
+            part_one_example = Some(example);
+            part_one_current = current;
+        }
+
+        // NOTE: the expected answer only holds for the extracted example, not for a kept one.
+        match pick_answer(candidates) {
+            Some(answer) if current => write_expected(day, part, &answer, part_file),
+            Some(answer) => println!(
+                "Expected answer of part {part} is {answer}, but the existing example \"{example_path}\" was kept."
+            ),
+            None => println!("No expected answer found for part {part}."),
+        }
+    }
+}
+
+fn pick_block(candidates: &PartCandidates) -> Option<String> {
+    let default = candidates.default_block()?;
+    let index = pick(
+        &format!("Example of part {}", candidates.part),
+        &candidates.blocks,
+        default,
+    );
+    Some(candidates.blocks[index].clone())
+}
+
+fn pick_answer(candidates: &PartCandidates) -> Option<String> {
+    let default = candidates.default_answer()?;
+    let index = pick(
+        &format!("Expected answer of part {}", candidates.part),
+        &candidates.answers,
+        default,
+    );
+    Some(candidates.answers[index].clone())
+}
+
+/// Let the user pick one of several candidates. Without a terminal, the default is picked.
+fn pick(title: &str, candidates: &[String], default: usize) -> usize {
+    if candidates.len() < 2 {
+        return default;
+    }
+
+    println!("{title}, {} candidates:", candidates.len());
+    for (i, candidate) in candidates.iter().enumerate() {
+        let mut lines = candidate.lines();
+        let first = lines.next().unwrap_or("");
+        let more = lines.count();
+        let more = if more > 0 {
+            format!(" (+{more} lines)")
+        } else {
+            String::new()
+        };
+        println!("  [{}] {first}{more}", i + 1);
+    }
+
+    if !io::stdin().is_terminal() {
+        println!("Picked [{}].", default + 1);
+        return default;
+    }
+
+    loop {
+        print!("Pick one [{}]: ", default + 1);
+        let _ = io::stdout().flush();
+
+        let mut line = String::new();
+        if io::stdin().read_line(&mut line).is_err() || line.trim().is_empty() {
+            return default;
+        }
+
+        match line.trim().parse::<usize>() {
+            Ok(n) if (1..=candidates.len()).contains(&n) => return n - 1,
+            _ => println!("Expected a number between 1 and {}.", candidates.len()),
+        }
+    }
+}
+
+/// Write an example file. Returns whether the file holds `example`, i.e. an existing file was not kept.
+fn write_example(path: &str, example: &str, overwrite: bool) -> bool {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+
+    if existing == example {
+        println!("Example \"{path}\" is up to date.");
+        return true;
+    }
+
+    if !existing.trim().is_empty() && !overwrite {
+        println!("Kept existing example \"{path}\", pass `--overwrite` to replace it.");
+        return false;
+    }
+
+    match fs::write(path, example) {
+        Ok(()) => {
+            println!("Wrote example to \"{path}\".");
+            true
+        }
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn write_expected(day: Day, part: u8, answer: &str, part_file: bool) {
+    let module_path = year_path(&format!("src/bin/{day}.rs"));
+
+    let Ok(source) = fs::read_to_string(&module_path) else {
+        println!(
+            "Expected answer of part {part} is {answer}, but \"{module_path}\" does not exist."
+        );
+        return;
+    };
+
+    let Some(updated) = examples::set_expected(&source, part, Some(answer), part_file) else {
+        println!(
+            "Expected answer of part {part} is {answer}, but its test was not found in \"{module_path}\"."
+        );
+        return;
+    };
+
+    match fs::write(&module_path, updated) {
+        Ok(()) => println!("Set expected answer of part {part} to {answer} in \"{module_path}\"."),
+        Err(e) => {
+            eprintln!("Failed to write module file: {e}");
+            process::exit(1);
+        }
+    }
+}
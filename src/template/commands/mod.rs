@@ -1,6 +1,15 @@
 pub mod all;
+pub mod countdown;
 pub mod download;
+pub mod examples;
 pub mod read;
+pub mod readme;
+pub mod rollover;
 pub mod scaffold;
+pub mod scale;
 pub mod solve;
+pub mod status;
+pub mod test_day;
 pub mod time;
+pub mod timings;
+pub mod verify;
@@ -0,0 +1,14 @@
+use std::process;
+
+use crate::template::readme;
+
+/// Update the generated sections of the readme, see `template::readme`.
+pub fn handle() {
+    match readme::update() {
+        Ok(sections) => println!("Updated {sections} README section(s)."),
+        Err(e) => {
+            eprintln!("Failed to update README: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::{fs, io, path::Path, process};
+
+use crate::template::{YEAR_ENV, Year, readme};
+
+const CONFIG_PATH: &str = ".cargo/config.toml";
+
+/// Data directories that are archived entry by entry, so that their `.keep` files stay in place.
+const DATA_DIRS: [&str; 4] = [
+    "data/inputs",
+    "data/examples",
+    "data/puzzles",
+    "data/reports",
+];
+
+/// Archive the active year below `years/<year>`, and make `next` (default: the following year) the active year.
+pub fn handle(next: Option<Year>) {
+    let Some(active) = Year::active() else {
+        eprintln!("{YEAR_ENV} is not set to a valid year, nothing to archive.");
+        process::exit(1);
+    };
+
+    let next = next.or_else(|| Year::new(active.into_inner() + 1)).unwrap();
+
+    if next == active {
+        eprintln!("{active} is already the active year.");
+        process::exit(1);
+    }
+
+    let archive = active.archive_root();
+
+    if Path::new(&archive).exists() {
+        eprintln!("\"{archive}\" already exists, {active} was archived before.");
+        process::exit(1);
+    }
+
+    if let Err(e) = archive_files(&archive) {
+        eprintln!("Failed to archive {active}: {e}");
+        process::exit(1);
+    }
+
+    println!("Archived {active} to \"{archive}\".");
+
+    match set_active_year(active, next) {
+        Ok(true) => println!("Set {YEAR_ENV} to {next} in \"{CONFIG_PATH}\"."),
+        Ok(false) => println!("Could not find {YEAR_ENV} in \"{CONFIG_PATH}\", set it to {next}."),
+        Err(e) => {
+            eprintln!("Failed to update \"{CONFIG_PATH}\": {e}");
+            process::exit(1);
+        }
+    }
+
+    println!("---");
+    println!("🎄 Pass `--year {active}` to any command to work on {active}.");
+}
+
+fn archive_files(archive: &str) -> Result<(), io::Error> {
+    move_entries("src/bin", &format!("{archive}/src/bin"))?;
+
+    for dir in DATA_DIRS {
+        move_entries(dir, &format!("{archive}/{dir}"))?;
+    }
+
+    // answers, submissions, timings and limits.
+    for entry in fs::read_dir("data")? {
+        let path = entry?.path();
+        if path.is_file() {
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            fs::rename(&path, format!("{archive}/data/{name}"))?;
+        }
+    }
+
+    // the archived README keeps the benchmarks of the year, the active one starts over along with the timings.
+    if Path::new("README.md").exists() {
+        fs::copy("README.md", format!("{archive}/README.md"))?;
+
+        let mut readme = fs::read_to_string("README.md")?;
+        readme::reset_benchmarks(&mut readme).map_err(io::Error::other)?;
+        fs::write("README.md", readme)?;
+    }
+
+    Ok(())
+}
+
+/// Move every entry of `from` to `to`, except for `.keep` files.
+fn move_entries(from: &str, to: &str) -> Result<(), io::Error> {
+    fs::create_dir_all(to)?;
+
+    let Ok(entries) = fs::read_dir(from) else {
+        return Ok(());
+    };
+
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_name() != ".keep" {
+            fs::rename(entry.path(), Path::new(to).join(entry.file_name()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Replace the active year in the cargo config. Returns `false` if it was not found.
+fn set_active_year(active: Year, next: Year) -> Result<bool, io::Error> {
+    let config = fs::read_to_string(CONFIG_PATH)?;
+    let current = format!("{YEAR_ENV} = \"{active}\"");
+
+    if !config.contains(&current) {
+        return Ok(false);
+    }
+
+    fs::write(
+        CONFIG_PATH,
+        config.replace(&current, &format!("{YEAR_ENV} = \"{next}\"")),
+    )?;
+    Ok(true)
+}
@@ -1,15 +1,15 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
-
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use crate::template::templates::{self, Variables};
+use crate::template::{Day, Year, year_path};
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+    create_parent_dir(path)?;
     let mut file = OpenOptions::new();
     if overwrite {
         file.create(true);
@@ -19,18 +19,38 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Create an empty file, unless it exists already, e.g. a downloaded input.
+fn create_file(path: &str) -> Result<Option<File>, std::io::Error> {
+    create_parent_dir(path)?;
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(file) => Ok(Some(file)),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Archived years may not have all directories yet.
+fn create_parent_dir(path: &str) -> Result<(), std::io::Error> {
+    match Path::new(path).parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Create the module file of a day from a template (default: `AOC_TEMPLATE`), and its empty data files.
+pub fn handle(day: Day, overwrite: bool, template: Option<&str>) {
+    let template = template.map_or_else(templates::default_name, String::from);
+
+    let module = templates::load(&template)
+        .and_then(|source| templates::render(&source, &Variables::for_day(day)))
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to render template \"{template}\": {e}");
+            process::exit(1);
+        });
+
+    let input_path = year_path(&format!("data/inputs/{day}.txt"));
+    let example_path = year_path(&format!("data/examples/{day}.txt"));
+    let module_path = year_path(&format!("src/bin/{day}.rs"));
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +60,7 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(module.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -55,7 +71,8 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&input_path) {
-        Ok(_) => {
+        Ok(None) => {}
+        Ok(Some(_)) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
         Err(e) => {
@@ -65,7 +82,8 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&example_path) {
-        Ok(_) => {
+        Ok(None) => {}
+        Ok(Some(_)) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
         Err(e) => {
@@ -75,5 +93,8 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    match Year::selected().filter(|year| !year.is_active()) {
+        Some(year) => println!("🎄 Type `cargo solve {day} --year {year}` to run your solution."),
+        None => println!("🎄 Type `cargo solve {day}` to run your solution."),
+    }
 }
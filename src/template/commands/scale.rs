@@ -0,0 +1,35 @@
+use std::process::{self, Command, Stdio};
+
+use crate::template::Day;
+use crate::template::run_multi::child_commands::{build_solutions, year_args};
+use crate::template::scale::ScaleOptions;
+
+pub fn handle(day: Day, part: Option<u8>, options: ScaleOptions) {
+    let bin = match build_solutions(true) {
+        Ok(bin) => bin,
+        Err(e) => {
+            eprintln!("Failed to build solutions: {e:?}");
+            process::exit(1);
+        }
+    };
+
+    let mut args = year_args();
+    args.push("--scale".into());
+    args.extend(options.to_args());
+
+    if let Some(part) = part {
+        args.extend(["--part".into(), part.to_string()]);
+    }
+
+    args.push(day.to_string());
+
+    let status = Command::new(bin)
+        .args(&args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status();
+
+    if !status.is_ok_and(|status| status.success()) {
+        process::exit(1);
+    }
+}
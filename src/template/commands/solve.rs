@@ -1,9 +1,17 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{Day, RunOptions, Year};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Run the solution of a day. Solutions of archived years are run through the `solutions` binary.
+pub fn handle(day: Day, release: bool, dhat: bool, options: &RunOptions) {
+    let archived = Year::selected().filter(|year| !year.is_active());
+
+    let bin = match archived {
+        Some(_) => "solutions".to_string(),
+        None => day.to_string(),
+    };
+
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), bin];
 
     if dhat {
         cmd_args.extend([
@@ -17,10 +25,10 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     }
 
     cmd_args.push("--".to_string());
+    cmd_args.extend(options.to_args());
 
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+    if let Some(year) = archived {
+        cmd_args.extend(["--year".to_string(), year.to_string(), day.to_string()]);
     }
 
     let mut cmd = Command::new("cargo")
@@ -0,0 +1,76 @@
+use std::process;
+
+use crate::template::answers::Answers;
+use crate::template::calendar::{Calendar, Clock, SystemClock};
+use crate::template::commands::test_day;
+use crate::template::run_multi::child_commands;
+use crate::template::status::{self, DayStatus};
+use crate::template::submissions::SubmissionLog;
+use crate::template::timings::Timings;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
+
+/// Print the progress of the selected year: a calendar of the stars, and a table of the days that are
+/// unlocked or have a solution. Unless `skip_examples` is set, the examples of every solution are checked.
+pub fn handle(skip_examples: bool) {
+    let Some(calendar) = Calendar::selected() else {
+        eprintln!("No year selected. Set AOC_YEAR or pass `--year`.");
+        process::exit(1);
+    };
+
+    let answers = Answers::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read known answers: {e}");
+        process::exit(1);
+    });
+
+    let log = SubmissionLog::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read submission log: {e}");
+        process::exit(1);
+    });
+
+    let timings = Timings::read_from_file();
+    let now = SystemClock.now();
+
+    let mut days: Vec<DayStatus> = (1..=calendar.days())
+        .filter_map(Day::new)
+        .map(|day| {
+            let unlocked = calendar.unlock(day) <= now;
+            DayStatus::read(day, unlocked, &answers, &log, &timings)
+        })
+        .collect();
+
+    if !skip_examples && days.iter().any(|status| status.solution) {
+        check_examples(&mut days);
+    }
+
+    let stars: usize = days.iter().map(DayStatus::stars).sum();
+
+    println!(
+        "{ANSI_BOLD}Advent of Code {}{ANSI_RESET}: {stars}/{} stars",
+        calendar.year(),
+        days.len() * 2
+    );
+    println!();
+    print!("{}", status::render_grid(&days));
+    println!();
+    print!("{}", status::render_table(&days));
+
+    if !timings.data.is_empty() {
+        println!();
+        println!("Total time: {:.2}ms", timings.total_millis());
+    }
+}
+
+/// Solve the examples of every day with a solution, with a debug build of the `solutions` bin.
+fn check_examples(days: &mut [DayStatus]) {
+    let bin = match child_commands::build_solutions(false) {
+        Ok(bin) => bin,
+        Err(e) => {
+            eprintln!("Failed to build solutions, examples are not checked: {e:?}");
+            return;
+        }
+    };
+
+    for status in days.iter_mut().filter(|status| status.solution) {
+        status.examples = test_day::count_matches(&bin, status.day);
+    }
+}
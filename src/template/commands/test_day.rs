@@ -0,0 +1,246 @@
+use std::{env, fs, path::Path, process};
+
+use crate::template::examples::{self, expected_literal};
+use crate::template::manifest::{Example, Manifest};
+use crate::template::run_multi::child_commands;
+use crate::template::runner::confirm;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, year_path};
+
+/// Where the expectation of an example is recorded.
+enum Origin {
+    /// The entry at this index of the day's manifest.
+    Manifest(usize),
+    /// The assertion of the `test_part_one` or `test_part_two` function of the solution.
+    Test,
+}
+
+struct Entry {
+    origin: Origin,
+    example: Example,
+    /// [`None`] if the solution panicked.
+    actual: Option<Option<String>>,
+}
+
+impl Entry {
+    fn matches(&self) -> bool {
+        self.actual.as_ref() == Some(&self.example.expected)
+    }
+}
+
+/// Solve every example of a day, both from its manifest and its example tests, and compare the answers
+/// against the recorded expectations. With `bless`, the expectations are rewritten after confirmation.
+pub fn handle(day: Day, bless: bool) {
+    let module_path = year_path(&format!("src/bin/{day}.rs"));
+
+    let Ok(source) = fs::read_to_string(&module_path) else {
+        eprintln!("Could not read \"{module_path}\". Run `cargo scaffold {day}` first.");
+        process::exit(1);
+    };
+
+    let manifest = Manifest::read(day).unwrap_or_else(|e| {
+        eprintln!("Invalid examples manifest: {e}");
+        process::exit(1);
+    });
+
+    let mut entries = collect_entries(day, &source, &manifest);
+
+    if entries.is_empty() {
+        println!("Day {day} has no examples.");
+        return;
+    }
+
+    let bin = child_commands::build_solutions(false).unwrap_or_else(|e| {
+        eprintln!("Failed to build solutions: {e:?}");
+        process::exit(1);
+    });
+
+    if let Err(e) = solve_entries(&bin, day, &mut entries, false) {
+        eprintln!("Failed to solve examples: {e}");
+        process::exit(1);
+    }
+
+    let mismatches = entries.iter().filter(|entry| !entry.matches()).count();
+
+    for entry in &entries {
+        print_entry(entry);
+    }
+
+    println!();
+
+    if mismatches == 0 {
+        println!(
+            "{ANSI_BOLD}All {} examples match.{ANSI_RESET}",
+            entries.len()
+        );
+        return;
+    }
+
+    println!(
+        "{ANSI_BOLD}{mismatches} of {} examples did not match.{ANSI_RESET}",
+        entries.len()
+    );
+
+    if !bless {
+        println!("Run `cargo test-day {day} --bless` to update the expectations.");
+        process::exit(1);
+    }
+
+    if entries
+        .iter()
+        .all(|entry| entry.actual.is_none() || entry.matches())
+    {
+        println!("Only panicking examples remain, nothing to update.");
+        process::exit(1);
+    }
+
+    if !confirm("Update the expectations to the current answers?") {
+        process::exit(1);
+    }
+
+    bless_entries(day, &entries, source, manifest);
+}
+
+/// The examples of the manifest, followed by those of the example tests.
+fn collect_entries(day: Day, source: &str, manifest: &Manifest) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = manifest
+        .examples
+        .iter()
+        .enumerate()
+        .map(|(index, example)| Entry {
+            origin: Origin::Manifest(index),
+            example: example.clone(),
+            actual: None,
+        })
+        .collect();
+
+    for (part, name) in [(1, "test_part_one"), (2, "test_part_two")] {
+        if let Some((file, expected)) = examples::test_expectation(source, day, part) {
+            entries.push(Entry {
+                origin: Origin::Test,
+                example: Example {
+                    name: name.into(),
+                    file,
+                    part,
+                    expected,
+                    params: vec![],
+                },
+                actual: None,
+            });
+        }
+    }
+
+    entries
+}
+
+/// The number of examples of a day that match their expectations, and the number of examples.
+/// Returns [`None`] if the day has no examples, or if they could not be solved with the `solutions` bin at `bin`.
+pub(crate) fn count_matches(bin: &Path, day: Day) -> Option<(usize, usize)> {
+    let source = fs::read_to_string(year_path(&format!("src/bin/{day}.rs"))).ok()?;
+    let manifest = Manifest::read(day).ok()?;
+    let mut entries = collect_entries(day, &source, &manifest);
+
+    if entries.is_empty() {
+        return None;
+    }
+
+    solve_entries(bin, day, &mut entries, true).ok()?;
+
+    let matches = entries.iter().filter(|entry| entry.matches()).count();
+    Some((matches, entries.len()))
+}
+
+/// Solve all entries in a single run of the `solutions` bin, with a manifest of all entries.
+fn solve_entries(bin: &Path, day: Day, entries: &mut [Entry], quiet: bool) -> Result<(), String> {
+    let manifest = Manifest {
+        examples: entries.iter().map(|entry| entry.example.clone()).collect(),
+    };
+
+    let manifest_path = env::temp_dir().join(format!("aoc-examples-{}-{day}.json", process::id()));
+
+    fs::write(&manifest_path, manifest.to_json(day))
+        .map_err(|e| format!("failed to write examples manifest: {e}"))?;
+
+    let records = child_commands::run_examples(bin, day, &manifest_path, quiet);
+
+    let _ = fs::remove_file(&manifest_path);
+
+    for record in records.map_err(|e| format!("{e:?}"))? {
+        let index = record
+            .input
+            .as_deref()
+            .and_then(|i| i.parse::<usize>().ok());
+        if let Some(entry) = index.and_then(|index| entries.get_mut(index)) {
+            entry.actual = Some(record.answer);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_entry(entry: &Entry) {
+    let example = &entry.example;
+    let label = format!(
+        "Part {} · {} ({})",
+        example.part, example.name, example.file
+    );
+
+    let expected = expected_literal(example.expected.as_deref());
+
+    match &entry.actual {
+        None => println!("  ✘ {label}: panicked"),
+        Some(_) if entry.matches() => println!("  ✔ {label}"),
+        Some(actual) => {
+            println!("  ✘ {label}");
+            println!("    - {expected}");
+            println!("    + {}", expected_literal(actual.as_deref()));
+        }
+    }
+}
+
+/// Rewrite the expectations of all entries that did not match, except for those that panicked.
+fn bless_entries(day: Day, entries: &[Entry], mut source: String, mut manifest: Manifest) {
+    let mut manifest_changed = false;
+    let mut source_changed = false;
+
+    for entry in entries.iter().filter(|entry| !entry.matches()) {
+        let Some(actual) = &entry.actual else {
+            continue;
+        };
+
+        match entry.origin {
+            Origin::Manifest(index) => {
+                manifest.examples[index].expected = actual.clone();
+                manifest_changed = true;
+            }
+            Origin::Test => {
+                if let Some(updated) =
+                    examples::set_expected(&source, entry.example.part, actual.as_deref(), false)
+                {
+                    source = updated;
+                    source_changed = true;
+                }
+            }
+        }
+    }
+
+    if manifest_changed {
+        match manifest.store(day) {
+            Ok(()) => println!("Updated \"{}\".", Manifest::path(day)),
+            Err(e) => {
+                eprintln!("Failed to write examples manifest: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    if source_changed {
+        let module_path = year_path(&format!("src/bin/{day}.rs"));
+        match fs::write(&module_path, source) {
+            Ok(()) => println!("Updated \"{module_path}\"."),
+            Err(e) => {
+                eprintln!("Failed to write module file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
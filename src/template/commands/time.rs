@@ -1,16 +1,37 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-use crate::template::run_multi::run_multi;
+use crate::template::compare::{self, Thresholds};
+use crate::template::history::{self, GitState};
+use crate::template::limits::LimitsConfig;
+use crate::template::report::Report;
+use crate::template::run_multi::{report_samples, run_multi_records};
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{Day, Limits, Year, all_days, readme};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    report: bool,
+    limits: Limits,
+    compare: Option<Thresholds>,
+    jobs: usize,
+) {
+    // NOTE: read before anything is written, the files written by this run would mark the tree as dirty.
+    let git_state = store.then(GitState::read);
     let stored_timings = Timings::read_from_file();
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
                 all_days().collect()
+            } else if compare.is_some() {
+                // when comparing, re-run the days that have stored timings.
+                stored_timings.data.iter().map(|t| t.day).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
                 all_days()
@@ -21,20 +42,63 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let limits = LimitsConfig::read_from_file().with_default(limits);
+    if jobs > 1 {
+        eprintln!("Warning: running days in parallel, timings may be skewed by contention.");
+    }
+
+    if report {
+        report_samples();
+    }
+
+    let (timings, records) = run_multi_records(&days_to_run, true, true, &limits, jobs);
+    let timings = timings.unwrap();
+
+    let regressed = compare.is_some_and(|thresholds| {
+        println!();
+        let comparisons = compare::compare(&stored_timings, &timings, &thresholds);
+        compare::print_report(&comparisons, &thresholds)
+    });
+
+    if report {
+        let report = Report {
+            year: Year::selected(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            timings: &timings,
+            records: &records,
+            previous: &stored_timings,
+            thresholds: compare.unwrap_or_default(),
+        };
+
+        println!();
+        match report.write() {
+            Ok(path) => println!("Wrote benchmark report to \"{path}\"."),
+            Err(e) => eprintln!("Failed to write benchmark report: {e}"),
+        }
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
+        if let Err(e) = history::append(&timings, &git_state.unwrap_or_default()) {
+            eprintln!("Failed to append to benchmark history: {e}");
+        }
+
         println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
+        match readme::update() {
+            Ok(_) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
             }
         }
     }
+
+    if regressed {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,78 @@
+use std::process;
+
+use crate::template::history::{self, HistoryEntry, format_nanos, format_timestamp, sparkline};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
+
+const PARTS: [(u8, &str); 3] = [(0, "Parse"), (1, "Part 1"), (2, "Part 2")];
+
+pub fn handle_history(day: Day, sparkline_only: bool) {
+    let entries = match history::read_day(day) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e}");
+            process::exit(1);
+        }
+    };
+
+    if entries.is_empty() {
+        println!(
+            "No benchmark history for day {day}. Run `cargo time {day} --store` to record one."
+        );
+        return;
+    }
+
+    println!(
+        "{ANSI_BOLD}Day {day}{ANSI_RESET} ({} benchmark runs)",
+        entries.len()
+    );
+    println!();
+
+    if !sparkline_only {
+        print_table(&entries);
+        println!();
+    }
+
+    for (part, label) in PARTS {
+        let values: Vec<Option<u64>> = entries.iter().map(|e| e.nanos(part)).collect();
+
+        if values.iter().all(Option::is_none) {
+            continue;
+        }
+
+        let present = values.iter().flatten();
+        println!(
+            "{label:<6}  {}  min {}, max {}, latest {}",
+            sparkline(&values),
+            format_nanos(present.clone().min().copied()),
+            format_nanos(present.max().copied()),
+            format_nanos(values.last().copied().flatten()),
+        );
+    }
+}
+
+fn print_table(entries: &[HistoryEntry]) {
+    println!(
+        "{:<16}  {:<15}  {:>10}  {:>10}  {:>10}",
+        "Date (UTC)", "Commit", "Parse", "Part 1", "Part 2"
+    );
+
+    for entry in entries {
+        let commit = entry
+            .commit
+            .as_deref()
+            .map_or("-", |c| &c[..c.len().min(7)]);
+        let commit = if entry.dirty {
+            format!("{commit} (dirty)")
+        } else {
+            commit.to_string()
+        };
+
+        println!(
+            "{:<16}  {commit:<15}  {:>10}  {:>10}  {:>10}",
+            format_timestamp(entry.timestamp),
+            format_nanos(entry.parse_nanos),
+            format_nanos(entry.part_1_nanos),
+            format_nanos(entry.part_2_nanos),
+        );
+    }
+}
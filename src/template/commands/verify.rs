@@ -0,0 +1,98 @@
+use std::process;
+
+use crate::template::answers::{Answers, answers_file_path};
+use crate::template::inputs::DEFAULT_INPUT_NAME;
+use crate::template::limits::LimitsConfig;
+use crate::template::run_multi::run_records;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Limits};
+
+/// Run the given days (or every day with known answers) in release and compare their answers
+/// against the known answers of the selected year. Exits with an error if any answer is wrong or missing,
+/// or if a day that was passed explicitly has no known answers.
+pub fn handle(days: Vec<Day>, limits: Limits, jobs: usize) {
+    let answers = Answers::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read known answers: {e}");
+        process::exit(1);
+    });
+
+    let mut days = if days.is_empty() {
+        answers.days()
+    } else {
+        days
+    };
+    days.sort_unstable();
+    days.dedup();
+
+    if days.is_empty() {
+        println!(
+            "No known answers in \"{}\", nothing to verify.",
+            answers_file_path()
+        );
+        return;
+    }
+
+    // NOTE: days without known answers would pass without checking anything.
+    let missing: Vec<Day> = days
+        .iter()
+        .copied()
+        .filter(|day| answers.for_day(*day).is_empty())
+        .collect();
+
+    let limits = LimitsConfig::read_from_file().with_default(limits);
+    let records = run_records(&days, true, false, &limits, jobs);
+
+    let mut failures = vec![];
+    let mut verified = 0;
+
+    for &day in &days {
+        for (input, part, expected) in answers.for_day(day) {
+            verified += 1;
+
+            let answer = records
+                .iter()
+                .find(|r| {
+                    r.day == day
+                        && r.part == part
+                        && r.input.as_deref().filter(|i| *i != DEFAULT_INPUT_NAME) == input
+                })
+                .and_then(|r| r.answer.as_deref());
+
+            if answer != Some(expected) {
+                let input = input.map(|i| format!(" ({i})")).unwrap_or_default();
+                failures.push(format!(
+                    "Day {day} Part {part}{input}: expected {expected}, got {}",
+                    answer.unwrap_or("nothing")
+                ));
+            }
+        }
+    }
+
+    println!();
+
+    if failures.is_empty() && missing.is_empty() {
+        println!("{ANSI_BOLD}Verified {verified} answers.{ANSI_RESET}");
+        return;
+    }
+
+    if !failures.is_empty() {
+        println!(
+            "{ANSI_BOLD}{} of {verified} answers did not match:{ANSI_RESET}",
+            failures.len()
+        );
+        for failure in failures {
+            println!("  ✘ {failure}");
+        }
+    }
+
+    if !missing.is_empty() {
+        println!(
+            "{ANSI_BOLD}No known answers in \"{}\" for:{ANSI_RESET}",
+            answers_file_path()
+        );
+        for day in missing {
+            println!("  ✘ Day {day}");
+        }
+    }
+
+    process::exit(1);
+}
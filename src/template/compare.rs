@@ -0,0 +1,315 @@
+//! Compares fresh benchmark results against the timings stored in `data/timings.json`.
+//!
+//! A change only counts as significant if it exceeds the noise threshold and, where spread information is
+//! available, the combined standard deviation of both measurements.
+
+use std::time::Duration;
+
+use crate::template::timings::{Timing, Timings};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
+
+/// Thresholds for `cargo time --compare`, both in percent of the stored timing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Thresholds {
+    /// Changes within this range are considered noise.
+    pub noise: f64,
+    /// Slowdowns beyond this limit fail the comparison.
+    pub max_regression: f64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            noise: 5.0,
+            max_regression: 10.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    Faster,
+    Noise,
+    Slower,
+    /// The part has a stored timing, but no current one, e.g. because it timed out.
+    Failed,
+}
+
+/// The change of a single part (or the parse stage, as part `0`) relative to its stored timing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartComparison {
+    pub day: Day,
+    pub part: u8,
+    pub baseline: Duration,
+    /// [`None`] if the part has no current measurement.
+    pub current: Option<Duration>,
+    /// Relative change in percent, positive values are slowdowns. Zero if the part has no current measurement.
+    pub percent: f64,
+    pub change: Change,
+}
+
+impl PartComparison {
+    pub fn is_regression(&self, thresholds: &Thresholds) -> bool {
+        match self.change {
+            Change::Failed => true,
+            Change::Slower => self.percent > thresholds.max_regression,
+            Change::Faster | Change::Noise => false,
+        }
+    }
+}
+
+/// Median and standard deviation of a part. Falls back to the formatted duration for timings stored without stats.
+pub fn measurement(timing: &Timing, part: u8) -> Option<(Duration, Option<Duration>)> {
+    let (formatted, stats) = match part {
+        0 => (&timing.parse, &timing.parse_stats),
+        1 => (&timing.part_1, &timing.part_1_stats),
+        2 => (&timing.part_2, &timing.part_2_stats),
+        _ => return None,
+    };
+
+    match stats {
+        Some(stats) => Some((stats.median, Some(stats.std_dev))),
+        None => parse_duration(formatted.as_deref()?).map(|median| (median, None)),
+    }
+}
+
+fn compare_part(
+    day: Day,
+    part: u8,
+    stored: &Timing,
+    current: &Timing,
+    thresholds: &Thresholds,
+) -> Option<PartComparison> {
+    let (baseline, baseline_std) = measurement(stored, part)?;
+
+    if baseline.is_zero() {
+        return None;
+    }
+
+    let Some((median, std)) = measurement(current, part) else {
+        return Some(PartComparison {
+            day,
+            part,
+            baseline,
+            current: None,
+            percent: 0.0,
+            change: Change::Failed,
+        });
+    };
+
+    let diff = median.as_secs_f64() - baseline.as_secs_f64();
+    let percent = diff / baseline.as_secs_f64() * 100.0;
+
+    let within_spread = match (baseline_std, std) {
+        (Some(a), Some(b)) => diff.abs() <= a.as_secs_f64().hypot(b.as_secs_f64()),
+        _ => false,
+    };
+
+    let change = if percent.abs() <= thresholds.noise || within_spread {
+        Change::Noise
+    } else if percent > 0.0 {
+        Change::Slower
+    } else {
+        Change::Faster
+    };
+
+    Some(PartComparison {
+        day,
+        part,
+        baseline,
+        current: Some(median),
+        percent,
+        change,
+    })
+}
+
+/// Compare every part of the days in `current` that has a stored timing.
+pub fn compare(
+    stored: &Timings,
+    current: &Timings,
+    thresholds: &Thresholds,
+) -> Vec<PartComparison> {
+    current
+        .data
+        .iter()
+        .filter_map(|timing| {
+            let baseline = stored.data.iter().find(|t| t.day == timing.day)?;
+            Some(
+                (0..=2).filter_map(|part| {
+                    compare_part(timing.day, part, baseline, timing, thresholds)
+                }),
+            )
+        })
+        .flatten()
+        .collect()
+}
+
+/// Print a comparison report. Returns `true` if any part regressed past the limit.
+pub fn print_report(comparisons: &[PartComparison], thresholds: &Thresholds) -> bool {
+    println!(
+        "{ANSI_BOLD}Comparison with stored timings{ANSI_RESET} (noise ±{}%, limit +{}%)",
+        thresholds.noise, thresholds.max_regression
+    );
+
+    if comparisons.is_empty() {
+        println!("No stored timings to compare against.");
+        return false;
+    }
+
+    for comparison in comparisons {
+        let label = match comparison.part {
+            0 => "Parse".to_string(),
+            part => format!("Part {part}"),
+        };
+
+        let verdict = match comparison.change {
+            Change::Failed => format!("{ANSI_BOLD}FAILED{ANSI_RESET}"),
+            _ if comparison.is_regression(thresholds) => {
+                format!("{ANSI_BOLD}REGRESSION{ANSI_RESET}")
+            }
+            Change::Faster => "faster".into(),
+            Change::Noise => "~ noise".into(),
+            Change::Slower => "slower".into(),
+        };
+
+        let (current, percent) = match comparison.current {
+            Some(current) => (
+                format!("{current:.1?}"),
+                format!("{:+.1}%", comparison.percent),
+            ),
+            None => ("-".into(), "-".into()),
+        };
+
+        println!(
+            "Day {} {label:<6}: {:>10} -> {current:>10} {percent:>9} {verdict}",
+            comparison.day,
+            format!("{:.1?}", comparison.baseline),
+        );
+    }
+
+    comparisons.iter().any(|c| c.is_regression(thresholds))
+}
+
+/// Parse a duration in the format produced by `{:.1?}`, e.g. `1.5ms` or `830.0ns`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let split = s.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value.parse().ok()?;
+
+    let nanos = match unit {
+        "ns" => value,
+        "µs" => value * 1e3,
+        "ms" => value * 1e6,
+        "s" => value * 1e9,
+        _ => return None,
+    };
+
+    Some(Duration::from_nanos(nanos.round() as u64))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Change, Thresholds, compare, parse_duration};
+    use crate::{
+        day,
+        template::{
+            stats::BenchStats,
+            timings::{Timing, Timings},
+        },
+    };
+
+    fn timing(part_1: &str, part_1_stats: Option<BenchStats>) -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                parse: None,
+                part_1: Some(part_1.into()),
+                part_2: None,
+                parse_stats: None,
+                part_1_stats,
+                part_2_stats: None,
+                parse_alloc: None,
+                part_1_alloc: None,
+                part_2_alloc: None,
+                inputs: vec![],
+                total_nanos: 0.0,
+            }],
+        }
+    }
+
+    fn stats(median_micros: u64, std_dev_micros: u64) -> BenchStats {
+        BenchStats {
+            median: Duration::from_micros(median_micros),
+            std_dev: Duration::from_micros(std_dev_micros),
+            ..BenchStats::single(Duration::from_micros(median_micros))
+        }
+    }
+
+    #[test]
+    fn parses_formatted_durations() {
+        assert_eq!(parse_duration("830.0ns"), Some(Duration::from_nanos(830)));
+        assert_eq!(parse_duration("1.5µs"), Some(Duration::from_nanos(1500)));
+        assert_eq!(parse_duration("12.3ms"), Some(Duration::from_micros(12300)));
+        assert_eq!(parse_duration("2.0s"), Some(Duration::from_secs(2)));
+        assert_eq!(parse_duration("TIMEOUT"), None);
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let thresholds = Thresholds::default();
+        let comparisons = compare(&timing("1.0ms", None), &timing("1.2ms", None), &thresholds);
+
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].change, Change::Slower);
+        assert!((comparisons[0].percent - 20.0).abs() < 1e-9);
+        assert_eq!(comparisons[0].is_regression(&thresholds), true);
+    }
+
+    #[test]
+    fn ignores_noise() {
+        let thresholds = Thresholds::default();
+
+        let within_threshold =
+            compare(&timing("1.0ms", None), &timing("1.03ms", None), &thresholds);
+        assert_eq!(within_threshold[0].change, Change::Noise);
+
+        // a 20% change is not significant if the measurements spread more than that.
+        let within_spread = compare(
+            &timing("1.0ms", Some(stats(1000, 200))),
+            &timing("1.2ms", Some(stats(1200, 200))),
+            &thresholds,
+        );
+        assert_eq!(within_spread[0].change, Change::Noise);
+        assert_eq!(within_spread[0].is_regression(&thresholds), false);
+    }
+
+    #[test]
+    fn reports_improvements() {
+        let comparisons = compare(
+            &timing("2.0ms", None),
+            &timing("1.0ms", None),
+            &Thresholds::default(),
+        );
+        assert_eq!(comparisons[0].change, Change::Faster);
+        assert!((comparisons[0].percent + 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn reports_failed_parts() {
+        let thresholds = Thresholds::default();
+        let comparisons = compare(
+            &timing("1.0ms", None),
+            &timing("TIMEOUT", None),
+            &thresholds,
+        );
+
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].change, Change::Failed);
+        assert_eq!(comparisons[0].current, None);
+        assert_eq!(comparisons[0].is_regression(&thresholds), true);
+    }
+}
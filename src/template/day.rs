@@ -2,13 +2,13 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
-#[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use crate::template::calendar::{Calendar, SystemClock};
 
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+/// The most days an event has had.
+const MAX_DAYS: u8 = 25;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Whether an event has the day is up to its [`Calendar`], e.g. the 2025 event has 12 days.
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -25,7 +25,7 @@ impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub const fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        if day == 0 || day > MAX_DAYS {
             return None;
         }
         Some(Self(day))
@@ -37,17 +37,10 @@ impl Day {
     }
 }
 
-#[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the day whose puzzle unlocked most recently while the selected year's event runs, `None` otherwise.
     pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
-        }
+        Calendar::selected()?.today(&SystemClock)
     }
 }
 
@@ -71,43 +64,64 @@ impl PartialOrd<u8> for Day {
 
 /* -------------------------------------------------------------------------- */
 
+/// Parses a day of the selected year's event.
 impl FromStr for Day {
     type Err = DayFromStrError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let days = event_days();
+        let day = s.parse().map_err(|_| DayFromStrError { days })?;
+        Self::new(day)
+            .filter(|day| day.0 <= days)
+            .ok_or(DayFromStrError { days })
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
 #[derive(Debug)]
-pub struct DayFromStrError;
+pub struct DayFromStrError {
+    /// The number of days of the event.
+    days: u8,
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(f, "expecting a day number between 1 and {}", self.days)
     }
 }
 
+/// The number of days of the selected year's event, or the most days an event has had if no year is selected.
+fn event_days() -> u8 {
+    Calendar::selected().map_or(MAX_DAYS, Calendar::days)
+}
+
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of the selected year's event, e.g. from the 1st to the 12th in 2025.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of an event from the 1st to its last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::until(event_days())
+    }
+
+    /// Yields the days from the 1st to `last`, which is capped at the 25th.
+    pub fn until(last: u8) -> Self {
+        Self {
+            current: 1,
+            last: last.min(MAX_DAYS),
+        }
     }
 }
 
@@ -115,7 +129,7 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
         // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
@@ -143,11 +157,11 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use super::{AllDays, Day, all_days};
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = AllDays::until(25);
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -176,6 +190,17 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_of_the_selected_event() {
+        // NOTE: the active year is set to 2025 in `.cargo/config.toml`, which has 12 days.
+        assert_eq!(all_days().last(), Some(Day(12)));
+        assert!("12".parse::<Day>().is_ok());
+        assert_eq!(
+            "13".parse::<Day>().unwrap_err().to_string(),
+            "expecting a day number between 1 and 12"
+        );
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,330 @@
+//! Extraction of examples and their expected answers from a puzzle description in `data/puzzles`.
+//!
+//! Examples are taken from the code blocks of each part, expected answers from the emphasized
+//! code that follows them, e.g. *`142`*. Usually the last emphasized value of a part is its answer.
+//!
+//! The expected answers in the example tests of a solution are read and rewritten here as well, see `cargo test-day --bless`.
+
+use crate::template::Day;
+
+/// Candidates for the example and the expected answer of one part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartCandidates {
+    pub part: u8,
+    /// Code blocks of the part, in order of appearance.
+    pub blocks: Vec<String>,
+    /// Emphasized values that follow the first code block of the part, in order of appearance.
+    pub answers: Vec<String>,
+}
+
+impl PartCandidates {
+    /// The most likely example: the first block that spans multiple lines.
+    pub fn default_block(&self) -> Option<usize> {
+        if self.blocks.is_empty() {
+            return None;
+        }
+
+        Some(
+            self.blocks
+                .iter()
+                .position(|block| block.trim_end().contains('\n'))
+                .unwrap_or(0),
+        )
+    }
+
+    /// The most likely answer: the last emphasized value.
+    pub fn default_answer(&self) -> Option<usize> {
+        self.answers.len().checked_sub(1)
+    }
+}
+
+/// Collect the candidates of both parts of a puzzle description in markdown.
+pub fn parse_puzzle(markdown: &str) -> Vec<PartCandidates> {
+    let mut parts = vec![PartCandidates {
+        part: 1,
+        ..PartCandidates::default()
+    }];
+
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        let current = parts.last_mut().unwrap();
+
+        if line.trim_start().starts_with("```") {
+            match block.take() {
+                Some(content) => current.blocks.push(content),
+                None => block = Some(String::new()),
+            }
+            continue;
+        }
+
+        if let Some(content) = &mut block {
+            // NOTE: some converters keep emphasis inside of code blocks.
+            content.push_str(&line.replace("<em>", "").replace("</em>", ""));
+            content.push('\n');
+            continue;
+        }
+
+        if line.contains("--- Part Two ---") {
+            parts.push(PartCandidates {
+                part: 2,
+                ..PartCandidates::default()
+            });
+            continue;
+        }
+
+        // answers of a part follow its example, if it has one.
+        if current.part == 1 && current.blocks.is_empty() {
+            continue;
+        }
+
+        current.answers.extend(emphasized_code(line));
+    }
+
+    parts
+}
+
+/// Values rendered as emphasized code, either *`value`* or `*value*`.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find(['*', '`']) {
+        let tail = &rest[start..];
+
+        let (open, close, emphasized) = if tail.starts_with("*`") {
+            ("*`", "`*", true)
+        } else if tail.starts_with("`*") {
+            ("`*", "*`", true)
+        } else if tail.starts_with('`') {
+            // skip plain code, so that its backticks are not mistaken for emphasis.
+            ("`", "`", false)
+        } else {
+            rest = &tail[1..];
+            continue;
+        };
+
+        let after = &tail[open.len()..];
+        let Some(end) = after.find(close) else {
+            break;
+        };
+
+        if emphasized && end > 0 {
+            values.push(after[..end].to_string());
+        }
+
+        rest = &after[end + close.len()..];
+    }
+
+    values
+}
+
+/// Locate the test of a part in the source of a solution.
+/// Returns the start of the test and the start and end of the expected value in its assertion.
+fn find_assertion(source: &str, part: u8) -> Option<(usize, usize, usize)> {
+    let name = match part {
+        1 => "fn test_part_one()",
+        2 => "fn test_part_two()",
+        _ => return None,
+    };
+
+    let test_start = source.find(name)?;
+    let assert_start = test_start + source[test_start..].find("assert_eq!(result, ")?;
+    let value_start = assert_start + "assert_eq!(result, ".len();
+    let value_end = value_start + source[value_start..].find(");")?;
+
+    Some((test_start, value_start, value_end))
+}
+
+/// The example file and expected answer of a `test_part_one` or `test_part_two` function in the source of a solution.
+/// Returns [`None`] if the test could not be found, or if its expected value is not a plain literal.
+pub fn test_expectation(source: &str, day: Day, part: u8) -> Option<(String, Option<String>)> {
+    let (test_start, value_start, value_end) = find_assertion(source, part)?;
+    let body = &source[test_start..value_start];
+
+    let file = if let Some(start) = body.find("read_file_part(\"examples\", DAY, ") {
+        let rest = &body[start + "read_file_part(\"examples\", DAY, ".len()..];
+        let file_part: u8 = rest[..rest.find(')')?].trim().parse().ok()?;
+        format!("{day}-{file_part}.txt")
+    } else if body.contains("read_file(\"examples\", DAY)") {
+        format!("{day}.txt")
+    } else {
+        return None;
+    };
+
+    Some((file, parse_literal(source[value_start..value_end].trim())?))
+}
+
+/// Parse a literal written by [`expected_literal`], or a similar string literal such as `Some("ABC".to_string())`.
+fn parse_literal(literal: &str) -> Option<Option<String>> {
+    if literal == "None" {
+        return Some(None);
+    }
+
+    let value = literal.strip_prefix("Some(")?.strip_suffix(')')?.trim();
+
+    if value.parse::<i128>().is_ok() {
+        return Some(Some(value.to_string()));
+    }
+
+    let value = value
+        .strip_prefix("String::from(")
+        .and_then(|value| value.strip_suffix(')'))
+        .or_else(|| {
+            [".to_string()", ".to_owned()", ".into()"]
+                .iter()
+                .find_map(|suffix| value.strip_suffix(suffix))
+        })
+        .unwrap_or(value);
+
+    let value = value.strip_prefix('"')?.strip_suffix('"')?;
+    (!value.contains(['"', '\\'])).then(|| Some(value.to_string()))
+}
+
+/// Set the expected answer of a `test_part_one` or `test_part_two` function in the source of a solution.
+/// With `part_file`, the test reads the part's own example file, e.g. `05-2.txt`.
+/// Returns [`None`] if the test or its assertion could not be found.
+pub fn set_expected(
+    source: &str,
+    part: u8,
+    expected: Option<&str>,
+    part_file: bool,
+) -> Option<String> {
+    let (test_start, value_start, value_end) = find_assertion(source, part)?;
+
+    let mut body = source[test_start..value_start].to_string();
+    if part_file {
+        body = body.replace(
+            "read_file(\"examples\", DAY)",
+            &format!("read_file_part(\"examples\", DAY, {part})"),
+        );
+    }
+
+    Some(format!(
+        "{}{body}{}{}",
+        &source[..test_start],
+        expected_literal(expected),
+        &source[value_end..]
+    ))
+}
+
+/// Render an answer as the value a solution returns, e.g. `Some(142)` or `Some(String::from("ABC"))`.
+pub fn expected_literal(answer: Option<&str>) -> String {
+    match answer {
+        None => "None".into(),
+        Some(answer) if answer.parse::<i128>().is_ok() => format!("Some({answer})"),
+        Some(answer) => format!("Some(String::from({answer:?}))"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartCandidates, parse_puzzle, set_expected, test_expectation};
+    use crate::day;
+
+    const PUZZLE: &str = "## --- Day 1: Trebuchet?! ---
+
+The newly-improved calibration document consists of lines of text, for example `1abc2`:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the calibration values are `12` and *`38`*. Adding these together produces *`50`*.
+
+## --- Part Two ---
+
+It looks like some of the digits are actually spelled out:
+
+```
+two1nine
+eightwothree
+```
+
+In this example, adding these together produces `*281*`.
+";
+
+    #[test]
+    fn parses_candidates() {
+        assert_eq!(
+            parse_puzzle(PUZZLE),
+            vec![
+                PartCandidates {
+                    part: 1,
+                    blocks: vec!["1abc2\npqr3stu8vwx\n".into()],
+                    answers: vec!["38".into(), "50".into()],
+                },
+                PartCandidates {
+                    part: 2,
+                    blocks: vec!["two1nine\neightwothree\n".into()],
+                    answers: vec!["281".into()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn picks_defaults() {
+        let candidates = PartCandidates {
+            part: 1,
+            blocks: vec!["x".into(), "a\nb\n".into()],
+            answers: vec!["1".into(), "2".into()],
+        };
+
+        assert_eq!(candidates.default_block(), Some(1));
+        assert_eq!(candidates.default_answer(), Some(1));
+        assert_eq!(PartCandidates::default().default_answer(), None);
+    }
+
+    #[test]
+    fn sets_expected_values() {
+        let source = r#"
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+"#;
+
+        let source = set_expected(source, 1, Some("50"), false).unwrap();
+        let source = set_expected(&source, 2, Some("ABC"), true).unwrap();
+
+        assert_eq!(
+            source,
+            r#"
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(50));
+    }
+
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file_part("examples", DAY, 2));
+        assert_eq!(result, Some(String::from("ABC")));
+    }
+"#
+        );
+        assert_eq!(set_expected("", 1, Some("1"), false), None);
+
+        assert_eq!(
+            test_expectation(&source, day!(4), 1),
+            Some(("04.txt".into(), Some("50".into())))
+        );
+        assert_eq!(
+            test_expectation(&source, day!(4), 2),
+            Some(("04-2.txt".into(), Some("ABC".into())))
+        );
+
+        let source = set_expected(&source, 1, None, false).unwrap();
+        assert_eq!(
+            test_expectation(&source, day!(4), 1),
+            Some(("04.txt".into(), None))
+        );
+    }
+}
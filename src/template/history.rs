@@ -0,0 +1,314 @@
+//! Append-only history of stored benchmarks.
+//!
+//! Every `cargo time --store` appends one JSON object per benchmarked day to `data/timings-history.jsonl`,
+//! together with the time of the run and the commit it was run against.
+
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::stats::BenchStats;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{Day, year_path};
+
+fn history_file_path() -> String {
+    year_path("data/timings-history.jsonl")
+}
+
+const SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// The benchmark results of a single day at one point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// The git `HEAD` the benchmark was run against, if available.
+    pub commit: Option<String>,
+    /// Whether the working tree had uncommitted changes.
+    pub dirty: bool,
+    pub day: Day,
+    pub parse_nanos: Option<u64>,
+    pub part_1_nanos: Option<u64>,
+    pub part_2_nanos: Option<u64>,
+}
+
+impl HistoryEntry {
+    fn from_timing(timing: &Timing, timestamp: u64, commit: Option<String>, dirty: bool) -> Self {
+        let nanos = |stats: &Option<BenchStats>| stats.as_ref().map(|s| s.median.as_nanos() as u64);
+
+        Self {
+            timestamp,
+            commit,
+            dirty,
+            day: timing.day,
+            parse_nanos: nanos(&timing.parse_stats),
+            part_1_nanos: nanos(&timing.part_1_stats),
+            part_2_nanos: nanos(&timing.part_2_stats),
+        }
+    }
+
+    /// Nanos of the parse stage (`0`) or a part.
+    pub fn nanos(&self, part: u8) -> Option<u64> {
+        match part {
+            0 => self.parse_nanos,
+            1 => self.part_1_nanos,
+            2 => self.part_2_nanos,
+            _ => None,
+        }
+    }
+}
+
+/// The state of the repository a benchmark was run against.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GitState {
+    /// The `HEAD` commit, outside of a git repository there is none.
+    pub commit: Option<String>,
+    /// Whether the working tree had uncommitted changes.
+    pub dirty: bool,
+}
+
+impl GitState {
+    /// Read the state of the working tree. This has to happen before a run writes any files,
+    /// otherwise the files written by the run itself mark the tree as dirty.
+    pub fn read() -> Self {
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+
+        Self {
+            commit: git(&["rev-parse", "HEAD"]),
+            dirty: git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty()),
+        }
+    }
+}
+
+/// Append the given timings to the history file, recorded against `git`.
+pub fn append(timings: &Timings, git: &GitState) -> Result<(), io::Error> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(history_file_path())?;
+
+    for timing in &timings.data {
+        let entry = HistoryEntry::from_timing(timing, timestamp, git.commit.clone(), git.dirty);
+        let line = JsonValue::from(&entry)
+            .stringify()
+            .map_err(io::Error::other)?;
+        writeln!(file, "{line}")?;
+    }
+
+    Ok(())
+}
+
+/// Read all history entries of a day, oldest first. If the history file is not present, returns no entries.
+pub fn read_day(day: Day) -> Result<Vec<HistoryEntry>, String> {
+    let content = match fs::read_to_string(history_file_path()) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    Ok(parse_entries(&content)?
+        .into_iter()
+        .filter(|e| e.day == day)
+        .collect())
+}
+
+fn parse_entries(content: &str) -> Result<Vec<HistoryEntry>, String> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).map_err(|e| e.to_string())?;
+            HistoryEntry::try_from(&json)
+        })
+        .collect()
+}
+
+/// Render values as a sparkline, scaled between their minimum and maximum. Missing values are rendered as a space.
+pub fn sparkline(values: &[Option<u64>]) -> String {
+    let present = values.iter().flatten();
+    let (Some(min), Some(max)) = (present.clone().min(), present.max()) else {
+        return " ".repeat(values.len());
+    };
+
+    values
+        .iter()
+        .map(|value| match value {
+            None => ' ',
+            Some(_) if max == min => SPARK_CHARS[SPARK_CHARS.len() / 2],
+            Some(v) => {
+                let index = (v - min) * (SPARK_CHARS.len() as u64 - 1) / (max - min);
+                SPARK_CHARS[index as usize]
+            }
+        })
+        .collect()
+}
+
+/// Format a unix timestamp as a UTC date and time, e.g. `2025-12-05 14:03`.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+
+    // civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        (seconds % 3600) / 60
+    )
+}
+
+/// Format nanos the same way timings are formatted, e.g. `1.5ms`.
+pub fn format_nanos(nanos: Option<u64>) -> String {
+    nanos.map_or_else(
+        || "-".into(),
+        |n| format!("{:.1?}", Duration::from_nanos(n)),
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            value
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("dirty".into(), JsonValue::Boolean(value.dirty));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, nanos) in [
+            ("parse_nanos", value.parse_nanos),
+            ("part_1_nanos", value.part_1_nanos),
+            ("part_2_nanos", value.part_2_nanos),
+        ] {
+            map.insert(
+                key.into(),
+                nanos.map_or(JsonValue::Null, |n| JsonValue::Number(n as f64)),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let commit = json.get("commit").and_then(|v| v.get::<String>()).cloned();
+
+        let dirty = json
+            .get("dirty")
+            .and_then(|v| v.get::<bool>().copied())
+            .unwrap_or(false);
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected entry.day to be a Day struct.")?;
+
+        let nanos = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|x| *x as u64)
+        };
+
+        Ok(HistoryEntry {
+            timestamp,
+            commit,
+            dirty,
+            day,
+            parse_nanos: nanos("parse_nanos"),
+            part_1_nanos: nanos("part_1_nanos"),
+            part_2_nanos: nanos("part_2_nanos"),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{HistoryEntry, format_timestamp, parse_entries, sparkline};
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = HistoryEntry {
+            timestamp: 1_764_936_000,
+            commit: Some("541a5ce".into()),
+            dirty: true,
+            day: day!(5),
+            parse_nanos: None,
+            part_1_nanos: Some(1200),
+            part_2_nanos: Some(3_400_000),
+        };
+
+        let line = JsonValue::from(&entry).stringify().unwrap();
+        let parsed = parse_entries(&format!("{line}\n\n{line}\n")).unwrap();
+        assert_eq!(parsed, vec![entry.clone(), entry]);
+    }
+
+    #[test]
+    fn renders_sparklines() {
+        assert_eq!(sparkline(&[Some(10), Some(20), None, Some(80)]), "▁▂ █");
+        assert_eq!(sparkline(&[Some(5), Some(5)]), "▅▅");
+        assert_eq!(sparkline(&[None, None]), "  ");
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_764_936_000), "2025-12-05 12:00");
+        assert_eq!(format_timestamp(951_825_600), "2000-02-29 12:00");
+    }
+}
@@ -0,0 +1,170 @@
+//! Conversion of the HTML served by adventofcode.com to markdown.
+//!
+//! This only covers the handful of tags used in puzzle descriptions and answer responses,
+//! all other tags are dropped and their text is kept.
+
+/// Convert all `<article>` elements of a page to markdown, e.g. the description of both parts of a puzzle.
+pub fn articles_to_markdown(html: &str) -> String {
+    let mut articles = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+
+        articles.push_str(&rest[start..start + end]);
+        rest = &rest[start + end + "</article>".len()..];
+    }
+
+    to_markdown(&articles)
+}
+
+/// Convert a fragment of HTML to markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut links: Vec<String> = vec![];
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut emphasized_code = false;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut out, rest, in_pre);
+            break;
+        };
+
+        push_text(&mut out, &rest[..start], in_pre);
+
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let (closing, name) = match tag.strip_prefix('/') {
+            Some(name) => (true, name),
+            None => (false, tag),
+        };
+        let name = name
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            // NOTE: answers are rendered as `<code><em>..</em></code>`, keep them recognizable as *`..`*.
+            ("code", false) if !in_pre => {
+                in_code = true;
+                emphasized_code = rest.starts_with("<em>");
+                out.push_str(if emphasized_code { "*`" } else { "`" });
+            }
+            ("code", true) if !in_pre => {
+                in_code = false;
+                out.push_str(if emphasized_code { "`*" } else { "`" });
+            }
+            ("em", _) if !in_pre && !in_code => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            ("br", _) => out.push('\n'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    let mut markdown = out.trim().to_string();
+    markdown.push('\n');
+    markdown
+}
+
+/// Append text, dropping whitespace-only text between block elements.
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    if !in_pre && text.trim().is_empty() && text.contains('\n') {
+        return;
+    }
+
+    out.push_str(&decode_entities(text));
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{articles_to_markdown, to_markdown};
+
+    #[test]
+    fn converts_puzzle_descriptions() {
+        let html = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is <em>wrong</em> with <a href="/2023/about">global snow</a>.</p>
+<ul><li>First</li><li>Second &amp; <code>x &lt; <em>3</em></code> is <code><em>true</em></code></li></ul>
+<pre><code>1abc2
+<em>pqr3stu8vwx</em>
+
+a1b2c3d4e5f
+</code></pre>
+</article>
+<p>Your puzzle answer was <code>142</code>.</p>
+</main>"#;
+
+        assert_eq!(
+            articles_to_markdown(html),
+            "## --- Day 1: Trebuchet?! ---\n\n\
+             Something is *wrong* with [global snow](/2023/about).\n\n\
+             - First\n- Second & `x < 3` is *`true`*\n\n\
+             ```\n1abc2\npqr3stu8vwx\n\na1b2c3d4e5f\n```\n"
+        );
+    }
+
+    #[test]
+    fn converts_responses() {
+        let html = r#"<article><p>That's not the right answer; your answer is too high. <a href="/2025/day/1">[Return to Day 1]</a></p></article>"#;
+
+        assert_eq!(
+            to_markdown(html),
+            "That's not the right answer; your answer is too high. [[Return to Day 1]](/2025/day/1)\n"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Discovery of the puzzle inputs of a day.
+//!
+//! Besides the default `data/inputs/NN.txt`, a day can have any number of named inputs, e.g. from different accounts:
+//! either `data/inputs/NN-<name>.txt` or `data/inputs/NN/<name>.txt`. Solutions are run against all of them.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{Day, year_path};
+
+/// Name used for `NN.txt` when a day has other inputs as well.
+pub const DEFAULT_INPUT_NAME: &str = "default";
+
+/// A puzzle input found on disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputFile {
+    /// [`None`] for the default input.
+    pub name: Option<String>,
+    pub path: PathBuf,
+}
+
+impl InputFile {
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(DEFAULT_INPUT_NAME)
+    }
+}
+
+/// A puzzle input along with its content.
+#[derive(Clone, Debug)]
+pub struct Input {
+    /// Label to report results under. [`None`] if the day only has its default input.
+    pub label: Option<String>,
+    pub content: String,
+}
+
+/// Find all inputs of a day in `dir`, the default input first and named inputs sorted by name.
+pub fn find_in(dir: &Path, day: Day) -> Vec<InputFile> {
+    let mut named: Vec<InputFile> = vec![];
+
+    let prefix = format!("{day}-");
+    for path in list_txt_files(dir) {
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        if let Some(name) = stem.strip_prefix(&prefix).filter(|n| !n.is_empty()) {
+            named.push(InputFile {
+                name: Some(name.to_string()),
+                path,
+            });
+        }
+    }
+
+    for path in list_txt_files(&dir.join(day.to_string())) {
+        if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+            named.push(InputFile {
+                name: Some(name.to_string()),
+                path,
+            });
+        }
+    }
+
+    named.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let default = dir.join(format!("{day}.txt"));
+    let mut inputs = vec![];
+
+    if default.is_file() {
+        inputs.push(InputFile {
+            name: None,
+            path: default,
+        });
+    }
+
+    inputs.extend(named);
+    inputs
+}
+
+/// Find all inputs of a day in `data/inputs`.
+pub fn find(day: Day) -> Vec<InputFile> {
+    find_in(Path::new(&year_path("data/inputs")), day)
+}
+
+/// Read all inputs of a day. Fails if a day has no inputs or one of them cannot be read.
+pub fn read_all(day: Day) -> Result<Vec<Input>, String> {
+    let files = find(day);
+
+    if files.is_empty() {
+        let dir = year_path("data/inputs");
+        return Err(format!(
+            "could not find input file \"{dir}/{day}.txt\" or named inputs in \"{dir}/{day}/\""
+        ));
+    }
+
+    files
+        .iter()
+        .zip(labels(&files))
+        .map(|(file, label)| {
+            let content = fs::read_to_string(&file.path).map_err(|e| {
+                format!("could not open input file \"{}\": {e}", file.path.display())
+            })?;
+
+            Ok(Input { label, content })
+        })
+        .collect()
+}
+
+/// Labels to report the results of `files` under. Inputs are only labelled if a day has more than its default input.
+pub fn labels(files: &[InputFile]) -> Vec<Option<String>> {
+    let labelled = files.len() > 1 || files.iter().any(|f| f.name.is_some());
+
+    files
+        .iter()
+        .map(|file| labelled.then(|| file.display_name().to_string()))
+        .collect()
+}
+
+fn list_txt_files(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| path.is_file() && path.extension().is_some_and(|e| e == "txt"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::find_in;
+    use crate::day;
+
+    #[test]
+    fn finds_named_inputs() {
+        let dir = env::temp_dir().join(format!("aoc-inputs-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("03")).unwrap();
+
+        for file in [
+            "03.txt",
+            "03-bob.txt",
+            "03/alice.txt",
+            "03/notes.md",
+            "04.txt",
+        ] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        let names: Vec<_> = find_in(&dir, day!(3))
+            .iter()
+            .map(|f| f.display_name().to_string())
+            .collect();
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(names, vec!["default", "alice", "bob"]);
+    }
+
+    #[test]
+    fn finds_nothing_in_missing_dir() {
+        assert_eq!(
+            find_in(&env::temp_dir().join("aoc-missing"), day!(1)),
+            vec![]
+        );
+    }
+}
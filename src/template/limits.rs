@@ -0,0 +1,258 @@
+//! Wall-clock and memory limits for solution runs.
+//!
+//! Limits are read from `data/limits.json` and can be set globally, per day and per part. The most specific value wins:
+//!
+//! ```json
+//! {
+//!   "time_limit_secs": 60,
+//!   "memory_limit_mb": 2048,
+//!   "days": {
+//!     "09": { "time_limit_secs": 120, "part_2": { "memory_limit_mb": 4096 } }
+//!   }
+//! }
+//! ```
+
+use std::{collections::HashMap, fs, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, year_path};
+
+fn limits_file_path() -> String {
+    year_path("data/limits.json")
+}
+
+/// The limits that apply to a single run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    pub time: Option<Duration>,
+    /// Maximum resident set size in bytes. On Linux, the address space of a run is limited to the same size.
+    pub memory: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_empty(&self) -> bool {
+        self.time.is_none() && self.memory.is_none()
+    }
+
+    /// Fill unset limits with the values of `fallback`.
+    #[must_use]
+    pub fn or(self, fallback: Limits) -> Self {
+        Self {
+            time: self.time.or(fallback.time),
+            memory: self.memory.or(fallback.memory),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct DayLimits {
+    day: Limits,
+    parts: HashMap<u8, Limits>,
+}
+
+/// The limits configured for all days.
+#[derive(Clone, Debug, Default)]
+pub struct LimitsConfig {
+    default: Limits,
+    days: HashMap<Day, DayLimits>,
+}
+
+impl LimitsConfig {
+    /// Read limits from the limits file. If not present, no limits apply.
+    pub fn read_from_file() -> Self {
+        match fs::read_to_string(limits_file_path()) {
+            Ok(content) => LimitsConfig::try_from(content).unwrap_or_else(|e| {
+                eprintln!(
+                    "Ignoring invalid limits file \"{}\": {e}",
+                    limits_file_path()
+                );
+                LimitsConfig::default()
+            }),
+            Err(_) => LimitsConfig::default(),
+        }
+    }
+
+    /// Override the global limits, e.g. with values passed on the command-line.
+    #[must_use]
+    pub fn with_default(mut self, limits: Limits) -> Self {
+        self.default = limits.or(self.default);
+        self
+    }
+
+    /// Whether no limits are configured at all.
+    pub fn is_empty(&self) -> bool {
+        self.default.is_empty()
+            && self
+                .days
+                .values()
+                .all(|d| d.day.is_empty() && d.parts.values().all(Limits::is_empty))
+    }
+
+    /// Resolve the limits for a part, preferring part over day over global values.
+    pub fn for_part(&self, day: Day, part: u8) -> Limits {
+        let Some(day_limits) = self.days.get(&day) else {
+            return self.default;
+        };
+
+        day_limits
+            .parts
+            .get(&part)
+            .copied()
+            .unwrap_or_default()
+            .or(day_limits.day)
+            .or(self.default)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<&JsonValue> for Limits {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected limits to be a JSON object.")?;
+
+        let number = |key: &str| -> Result<Option<f64>, String> {
+            match json.get(key) {
+                None => Ok(None),
+                Some(v) if v.is_null() => Ok(None),
+                Some(v) => v
+                    .get::<f64>()
+                    .copied()
+                    .filter(|x| *x > 0.0)
+                    .map(Some)
+                    .ok_or(format!("Expected limits.{key} to be a positive number.")),
+            }
+        };
+
+        Ok(Limits {
+            time: number("time_limit_secs")?.map(Duration::from_secs_f64),
+            memory: number("memory_limit_mb")?.map(|mb| (mb * 1024.0 * 1024.0) as u64),
+        })
+    }
+}
+
+impl TryFrom<String> for LimitsConfig {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let default = Limits::try_from(&json)?;
+
+        let mut days = HashMap::new();
+
+        if let Some(json_days) = json
+            .get::<HashMap<String, JsonValue>>()
+            .and_then(|map| map.get("days"))
+        {
+            let json_days = json_days
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected `json.days` to be an object.")?;
+
+            for (key, value) in json_days {
+                let day = Day::from_str(key).map_err(|e| format!("{key}: {e}"))?;
+                let mut day_limits = DayLimits {
+                    day: Limits::try_from(value)?,
+                    parts: HashMap::new(),
+                };
+
+                for part in [1, 2] {
+                    if let Some(part_value) = value
+                        .get::<HashMap<String, JsonValue>>()
+                        .and_then(|map| map.get(&format!("part_{part}")))
+                    {
+                        day_limits.parts.insert(part, Limits::try_from(part_value)?);
+                    }
+                }
+
+                days.insert(day, day_limits);
+            }
+        }
+
+        Ok(LimitsConfig { default, days })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Limits, LimitsConfig};
+    use crate::day;
+
+    fn get_mock_config() -> LimitsConfig {
+        let json = r#"{
+            "time_limit_secs": 60,
+            "days": {
+                "09": { "time_limit_secs": 120, "memory_limit_mb": 1, "part_2": { "time_limit_secs": 0.5 } }
+            }
+        }"#;
+        LimitsConfig::try_from(json.to_string()).unwrap()
+    }
+
+    #[test]
+    fn resolves_most_specific_limits() {
+        let config = get_mock_config();
+
+        assert_eq!(
+            config.for_part(day!(1), 1),
+            Limits {
+                time: Some(Duration::from_secs(60)),
+                memory: None
+            }
+        );
+        assert_eq!(
+            config.for_part(day!(9), 1),
+            Limits {
+                time: Some(Duration::from_secs(120)),
+                memory: Some(1024 * 1024)
+            }
+        );
+        assert_eq!(
+            config.for_part(day!(9), 2),
+            Limits {
+                time: Some(Duration::from_millis(500)),
+                memory: Some(1024 * 1024)
+            }
+        );
+    }
+
+    #[test]
+    fn overrides_default_limits() {
+        let config = get_mock_config().with_default(Limits {
+            time: Some(Duration::from_secs(5)),
+            memory: Some(10),
+        });
+
+        assert_eq!(
+            config.for_part(day!(1), 2),
+            Limits {
+                time: Some(Duration::from_secs(5)),
+                memory: Some(10)
+            }
+        );
+        assert_eq!(
+            config.for_part(day!(9), 2).time,
+            Some(Duration::from_millis(500))
+        );
+    }
+
+    #[test]
+    fn handles_empty_config() {
+        let config = LimitsConfig::try_from("{}".to_string()).unwrap();
+        assert_eq!(config.is_empty(), true);
+        assert_eq!(config.for_part(day!(1), 1), Limits::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_limits() {
+        LimitsConfig::try_from(r#"{ "time_limit_secs": "soon" }"#.to_string()).unwrap();
+    }
+}
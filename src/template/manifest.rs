@@ -0,0 +1,352 @@
+//! Examples of a day with their expected answers, stored in `data/examples/NN.json`:
+//!
+//! ```json
+//! [
+//!   { "file": "10.txt", "part": 1, "expected": 36 },
+//!   { "name": "larger", "file": "10-2.txt", "part": 1, "expected": "81", "params": { "size": 7 } }
+//! ]
+//! ```
+//!
+//! `file` is relative to `data/examples` and defaults to `NN.txt`. `name` defaults to the file name without extension.
+//! An `expected` answer of `null` expects the solution to return `None`, e.g. while a part is not solved yet.
+//! Answers beyond 2^53 have to be strings, as numbers are read as `f64`.
+//!
+//! `build.rs` turns every entry into a named test of the day, e.g. `examples::part_one_larger`.
+//! Solutions read the `params` of the running example with [`param`], to e.g. scale a grid down to the example's size.
+
+use std::{cell::RefCell, collections::HashMap, fs, io, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::registry::Registry;
+use crate::template::{Day, year_path};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub file: String,
+    pub part: u8,
+    pub expected: Option<String>,
+    pub params: Vec<(String, String)>,
+}
+
+impl Example {
+    pub fn path(&self) -> String {
+        year_path(&format!("data/examples/{}", self.file))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub examples: Vec<Example>,
+}
+
+impl Manifest {
+    pub fn path(day: Day) -> String {
+        year_path(&format!("data/examples/{day}.json"))
+    }
+
+    /// Read the manifest of a day. If it is not present, the day has no examples.
+    pub fn read(day: Day) -> Result<Self, String> {
+        match fs::read_to_string(Self::path(day)) {
+            Ok(content) => Manifest::parse(day, &content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Manifest::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn parse(day: Day, s: &str) -> Result<Self, String> {
+        let json = JsonValue::from_str(s).map_err(|e| e.to_string())?;
+
+        let entries = json
+            .get::<Vec<JsonValue>>()
+            .ok_or("Expected the examples manifest to be a JSON array.")?;
+
+        let examples = entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| parse_example(day, i, entry))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Manifest { examples })
+    }
+
+    pub fn store(&self, day: Day) -> Result<(), io::Error> {
+        fs::write(Self::path(day), self.to_json(day))
+    }
+
+    /// Render the manifest with one example per line. Fields that match their defaults are left out.
+    pub fn to_json(&self, day: Day) -> String {
+        let lines: Vec<String> = self
+            .examples
+            .iter()
+            .map(|example| {
+                let mut fields = vec![];
+
+                if example.name != default_name(&example.file) {
+                    fields.push(format!("\"name\": {}", json_string(&example.name)));
+                }
+                if example.file != format!("{day}.txt") {
+                    fields.push(format!("\"file\": {}", json_string(&example.file)));
+                }
+
+                fields.push(format!("\"part\": {}", example.part));
+                fields.push(format!(
+                    "\"expected\": {}",
+                    example
+                        .expected
+                        .as_deref()
+                        .map_or("null".into(), json_answer)
+                ));
+
+                if !example.params.is_empty() {
+                    let params: Vec<String> = example
+                        .params
+                        .iter()
+                        .map(|(key, value)| format!("{}: {}", json_string(key), json_answer(value)))
+                        .collect();
+                    fields.push(format!("\"params\": {{ {} }}", params.join(", ")));
+                }
+
+                format!("  {{ {} }}", fields.join(", "))
+            })
+            .collect();
+
+        if lines.is_empty() {
+            "[]\n".into()
+        } else {
+            format!("[\n{}\n]\n", lines.join(",\n"))
+        }
+    }
+}
+
+fn default_name(file: &str) -> &str {
+    file.rsplit_once('.').map_or(file, |(stem, _)| stem)
+}
+
+fn json_string(s: &str) -> String {
+    JsonValue::String(s.to_string())
+        .stringify()
+        .unwrap_or_default()
+}
+
+/// Answers are written as numbers if they survive the roundtrip through a JSON number (an `f64`).
+fn json_answer(answer: &str) -> String {
+    match answer.parse::<i64>() {
+        Ok(n) if n.unsigned_abs() < 1 << 53 && n.to_string() == answer => answer.to_string(),
+        _ => json_string(answer),
+    }
+}
+
+fn parse_example(day: Day, index: usize, entry: &JsonValue) -> Result<Example, String> {
+    let entry = entry
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or(format!("Expected examples[{index}] to be an object."))?;
+
+    let file = match entry.get("file") {
+        Some(JsonValue::String(file)) => file.clone(),
+        None => format!("{day}.txt"),
+        Some(_) => return Err(format!("Expected examples[{index}].file to be a string.")),
+    };
+
+    let part = match entry.get("part") {
+        Some(JsonValue::Number(part)) if *part == 1.0 || *part == 2.0 => *part as u8,
+        _ => return Err(format!("Expected examples[{index}].part to be 1 or 2.")),
+    };
+
+    let name = match entry.get("name") {
+        Some(JsonValue::String(name)) => name.clone(),
+        None => default_name(&file).into(),
+        Some(_) => return Err(format!("Expected examples[{index}].name to be a string.")),
+    };
+
+    // answers are compared as strings, but numbers are accepted for convenience.
+    let expected = match entry.get("expected") {
+        Some(JsonValue::Null) | None => None,
+        Some(value) => Some(value_to_string(value).ok_or(format!(
+            "Expected examples[{index}].expected to be a string or number."
+        ))?),
+    };
+
+    let params = match entry.get("params") {
+        Some(JsonValue::Object(params)) => {
+            let mut params = params
+                .iter()
+                .map(|(key, value)| {
+                    let value = value_to_string(value).ok_or(format!(
+                        "Expected examples[{index}].params.{key} to be a string or number."
+                    ))?;
+                    Ok((key.clone(), value))
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            params.sort_unstable();
+            params
+        }
+        None => vec![],
+        Some(_) => {
+            return Err(format!(
+                "Expected examples[{index}].params to be an object."
+            ));
+        }
+    };
+
+    Ok(Example {
+        name,
+        file,
+        part,
+        expected,
+        params,
+    })
+}
+
+fn value_to_string(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::String(s) => Some(s.clone()),
+        JsonValue::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+thread_local! {
+    static PARAMS: RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A parameter of the example that is currently solved on this thread.
+/// Returns [`None`] for the real input, or if the example does not set the parameter.
+pub fn param<T: FromStr>(name: &str) -> Option<T> {
+    PARAMS.with_borrow(|params| {
+        params
+            .iter()
+            .find(|(key, _)| key == name)
+            .and_then(|(_, value)| value.parse().ok())
+    })
+}
+
+/// Solve an example with the given parameters, see [`param`].
+pub fn solve_example(
+    solve: impl FnOnce(&str) -> Option<String>,
+    example: &Example,
+) -> Option<String> {
+    let input = fs::read_to_string(example.path())
+        .unwrap_or_else(|e| panic!("could not open example file \"{}\": {e}", example.path()));
+
+    PARAMS.set(example.params.clone());
+    let answer = solve(&input);
+    PARAMS.set(vec![]);
+
+    answer
+}
+
+/// Check an entry of a day's manifest. Called by the tests that `build.rs` generates.
+pub fn check_example(register: fn(&mut Registry), day: Day, index: usize) {
+    let manifest = Manifest::read(day).unwrap_or_else(|e| panic!("invalid examples manifest: {e}"));
+
+    let example = manifest
+        .examples
+        .get(index)
+        .expect("examples manifest changed, rebuild to update the tests");
+
+    let mut registry = Registry::new();
+    register(&mut registry);
+    let solver = registry.solver(day).expect("day is registered");
+
+    let answer = solve_example(|input| solver(input, example.part), example);
+
+    assert_eq!(
+        answer, example.expected,
+        "example \"{}\" ({}) of part {}",
+        example.name, example.file, example.part
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, Manifest, param, solve_example};
+    use crate::day;
+
+    #[test]
+    fn parses_manifests() {
+        let manifest = Manifest::parse(
+            day!(10),
+            r#"[
+                { "part": 1, "expected": 36 },
+                { "name": "larger", "file": "10-2.txt", "part": 2, "expected": null, "params": { "size": 7, "mode": "fast" } }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.examples,
+            vec![
+                Example {
+                    name: "10".into(),
+                    file: "10.txt".into(),
+                    part: 1,
+                    expected: Some("36".into()),
+                    params: vec![],
+                },
+                Example {
+                    name: "larger".into(),
+                    file: "10-2.txt".into(),
+                    part: 2,
+                    expected: None,
+                    params: vec![("mode".into(), "fast".into()), ("size".into(), "7".into())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let manifest = Manifest::parse(
+            day!(3),
+            r#"[
+                { "part": 1, "expected": "0042" },
+                { "name": "big", "file": "03-2.txt", "part": 2, "expected": "12345678901234567890", "params": { "size": 7 } },
+                { "file": "03-2.txt", "part": 2, "expected": null }
+            ]"#,
+        )
+        .unwrap();
+
+        let json = manifest.to_json(day!(3));
+        assert_eq!(
+            json,
+            r#"[
+  { "part": 1, "expected": "0042" },
+  { "name": "big", "file": "03-2.txt", "part": 2, "expected": "12345678901234567890", "params": { "size": 7 } },
+  { "file": "03-2.txt", "part": 2, "expected": null }
+]
+"#
+        );
+        assert_eq!(Manifest::parse(day!(3), &json).unwrap(), manifest);
+    }
+
+    #[test]
+    fn rejects_invalid_parts() {
+        assert!(Manifest::parse(day!(1), r#"[{ "part": 3 }]"#).is_err());
+        assert!(Manifest::parse(day!(1), r#"{ "part": 1 }"#).is_err());
+    }
+
+    #[test]
+    fn sets_params_while_solving() {
+        let example = Example {
+            name: "01".into(),
+            file: "01.txt".into(),
+            part: 1,
+            expected: None,
+            params: vec![("size".into(), "7".into())],
+        };
+
+        let answer = solve_example(
+            |_| param::<usize>("size").map(|size| size.to_string()),
+            &example,
+        );
+
+        assert_eq!(answer, Some("7".into()));
+        assert_eq!(param::<usize>("size"), None);
+    }
+}
@@ -1,15 +1,57 @@
 use std::{env, fs};
 
 pub mod aoc_cli;
+pub mod aoc_client;
+pub mod backend;
+pub mod calendar;
 pub mod commands;
+pub mod manifest;
+pub mod registry;
 pub mod runner;
 
+pub use compare::Thresholds;
 pub use day::*;
+pub use limits::Limits;
+pub use manifest::param;
+pub use options::{InputSource, RunOptions};
+pub use scale::ScaleOptions;
+pub use solution::Solution;
+pub use submissions::{Hint, SubmitOutcome};
+pub use year::*;
 
+mod alloc;
+mod answers;
+mod compare;
 mod day;
+mod examples;
+mod history;
+mod html;
+mod inputs;
+mod limits;
+mod options;
+mod readme;
 mod readme_benchmarks;
+mod record;
+mod report;
 mod run_multi;
+mod scale;
+mod solution;
+mod stats;
+mod status;
+mod submissions;
+mod templates;
 mod timings;
+mod year;
+
+// NOTE: the allocator lives in the library so that the aggregate `solutions` binary,
+// which includes every day as a module, does not end up with one allocator per day.
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[cfg(not(feature = "dhat-heap"))]
+#[global_allocator]
+static ALLOC: alloc::CountingAlloc = alloc::CountingAlloc;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -19,7 +61,10 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(year_path("data"))
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
@@ -29,7 +74,7 @@ pub fn read_file(folder: &str, day: Day) -> String {
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(year_path("data"))
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
@@ -39,30 +84,110 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Alternatively, the second parameter can name a type implementing [`Solution`], which parses
+/// the input once and shares the result between both parts.
+///
+/// A trailing `generator = func` registers a function that generates inputs of a given size,
+/// which is used by `cargo scale` to benchmark how a solution scales with its input:
+///
+/// ```ignore
+/// advent_of_code::solution!(8, generator = generate);
+///
+/// pub fn generate(size: usize) -> String { /* ... */ }
+/// ```
+///
+/// Every entry of the day's examples manifest becomes a test in the module `examples`, see [`manifest`].
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, [] [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, generator = $generator:path) => {
+        $crate::solution!(@impl $day, [$generator] [part_one, 1] [part_two, 2]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, [] [part_one, 1]);
+    };
+    ($day:expr, 1, generator = $generator:path) => {
+        $crate::solution!(@impl $day, [$generator] [part_one, 1]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, [] [part_two, 2]);
+    };
+    ($day:expr, 2, generator = $generator:path) => {
+        $crate::solution!(@impl $day, [$generator] [part_two, 2]);
+    };
+    ($day:expr, $solution:ty) => {
+        $crate::solution!(@trait $day, $solution, []);
+    };
+    ($day:expr, $solution:ty, generator = $generator:path) => {
+        $crate::solution!(@trait $day, $solution, [$generator]);
+    };
+
+    (@trait $day:expr, $solution:ty, [$($generator:path)?]) => {
+        /// The current day.
+        const DAY: $crate::template::Day = $crate::day!($day);
+
+        /// Registers this day with the aggregate `solutions` binary.
+        #[allow(dead_code)]
+        pub fn register(registry: &mut $crate::template::registry::Registry) {
+            use $crate::template::Solution;
+
+            registry.register(DAY, |input: &str| {
+                $crate::template::runner::run_solution::<$solution>(input, DAY)
+            });
+            registry.register_solver(DAY, |input: &str, part: u8| match part {
+                1 => <$solution>::solve_part_one(input).map(|answer| answer.to_string()),
+                2 => <$solution>::solve_part_two(input).map(|answer| answer.to_string()),
+                _ => None,
+            });
+            $( registry.register_generator(DAY, $generator); )?
+        }
+
+        fn main() {
+            $crate::template::runner::run_main(DAY, |input: &str| {
+                $crate::template::runner::run_solution::<$solution>(input, DAY)
+            });
+        }
+
+        /// Tests generated from the examples manifest of the day.
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(env!("OUT_DIR"), "/examples_", env!("CARGO_BIN_NAME"), ".rs"));
+        }
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, [$($generator:path)?] $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// Registers this day with the aggregate `solutions` binary.
+        #[allow(dead_code)]
+        pub fn register(registry: &mut $crate::template::registry::Registry) {
+            registry.register(DAY, |input: &str| {
+                use $crate::template::runner::*;
+                $( run_part($func, input, DAY, $part); )*
+            });
+            registry.register_solver(DAY, |input: &str, part: u8| {
+                $( if part == $part {
+                    return $func(input).map(|answer| answer.to_string());
+                } )*
+                None
+            });
+            $( registry.register_generator(DAY, $generator); )?
+        }
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            run_main(DAY, |input: &str| {
+                $( run_part($func, input, DAY, $part); )*
+            });
+        }
+
+        /// Tests generated from the examples manifest of the day.
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(env!("OUT_DIR"), "/examples_", env!("CARGO_BIN_NAME"), ".rs"));
         }
     };
 }
@@ -0,0 +1,225 @@
+//! Command-line options of solution binaries.
+//!
+//! ```text
+//! cargo run --release --bin 05 -- [INPUT | -] [--part 1|2] [--repeat N] [--quiet] [--time [--samples]] [--submit 1|2 [--wait]]
+//! ```
+//!
+//! `INPUT` (or `--input <path>`) runs the solution against a single file instead of the inputs in `data/inputs`,
+//! `-` (or `--stdin`) reads the input from stdin.
+
+use std::{
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    sync::OnceLock,
+};
+
+/// Where a solution reads its input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// `-` stands for stdin, anything else is a path.
+    pub fn parse(value: &str) -> Self {
+        if value == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(value.into())
+        }
+    }
+
+    /// Label to report results of this input under.
+    pub fn label(&self) -> String {
+        match self {
+            InputSource::Path(path) => path.display().to_string(),
+            InputSource::Stdin => "stdin".into(),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            InputSource::Path(path) => fs::read_to_string(path)
+                .map_err(|e| format!("could not open input file \"{}\": {e}", path.display())),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("could not read input from stdin: {e}"))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    pub input: Option<InputSource>,
+    /// Only run this part.
+    pub part: Option<u8>,
+    /// Run every part this many times and report statistics over all runs.
+    pub repeat: Option<u32>,
+    /// Only print answers, one per line.
+    pub quiet: bool,
+    /// Bench every part.
+    pub time: bool,
+    /// Report the raw samples of every benched part, see `cargo time --report`.
+    pub samples: bool,
+    /// Submit the answer of this part.
+    pub submit: Option<u8>,
+    /// Wait out submission cooldowns instead of giving up.
+    pub wait: bool,
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value.parse() {
+        Ok(part @ (1 | 2)) => Ok(part),
+        _ => Err(format!("expected part 1 or 2, got `{value}`")),
+    }
+}
+
+fn parse_repeat(value: &str) -> Result<u32, String> {
+    match value.parse() {
+        Ok(repeat) if repeat > 0 => Ok(repeat),
+        _ => Err(format!("expected a positive number of runs, got `{value}`")),
+    }
+}
+
+impl RunOptions {
+    /// Parse the options, leaving free arguments (e.g. days or an input path) in `args`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let stdin = args.contains("--stdin");
+        let input: Option<String> = args.opt_value_from_str("--input")?;
+
+        Ok(Self {
+            input: input
+                .map(|input| InputSource::parse(&input))
+                .or(stdin.then_some(InputSource::Stdin)),
+            part: args.opt_value_from_fn("--part", parse_part)?,
+            repeat: args.opt_value_from_fn("--repeat", parse_repeat)?,
+            quiet: args.contains("--quiet"),
+            time: args.contains("--time"),
+            samples: args.contains("--samples"),
+            submit: args.opt_value_from_fn("--submit", parse_part)?,
+            wait: args.contains("--wait"),
+        })
+    }
+
+    /// Forward the options to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        match &self.input {
+            Some(InputSource::Path(path)) => {
+                args.extend(["--input".into(), path.display().to_string()]);
+            }
+            Some(InputSource::Stdin) => args.push("--stdin".into()),
+            None => {}
+        }
+
+        if let Some(part) = self.part {
+            args.extend(["--part".into(), part.to_string()]);
+        }
+
+        if let Some(repeat) = self.repeat {
+            args.extend(["--repeat".into(), repeat.to_string()]);
+        }
+
+        if self.quiet {
+            args.push("--quiet".into());
+        }
+
+        if self.time {
+            args.push("--time".into());
+        }
+
+        if self.samples {
+            args.push("--samples".into());
+        }
+
+        if let Some(submit) = self.submit {
+            args.extend(["--submit".into(), submit.to_string()]);
+        }
+
+        if self.wait {
+            args.push("--wait".into());
+        }
+
+        args
+    }
+}
+
+/// The options the current process was started with. Arguments that are not options are ignored here.
+pub fn current() -> &'static RunOptions {
+    static OPTIONS: OnceLock<RunOptions> = OnceLock::new();
+
+    OPTIONS.get_or_init(|| {
+        let mut args = pico_args::Arguments::from_env();
+        RunOptions::from_args(&mut args).unwrap_or_else(|e| {
+            eprintln!("Invalid arguments: {e}");
+            std::process::exit(1);
+        })
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::{InputSource, RunOptions};
+
+    fn parse(args: &[&str]) -> Result<(RunOptions, Vec<OsString>), pico_args::Error> {
+        let mut args = pico_args::Arguments::from_vec(args.iter().map(OsString::from).collect());
+        let options = RunOptions::from_args(&mut args)?;
+        Ok((options, args.finish()))
+    }
+
+    #[test]
+    fn parses_options() {
+        let (options, free) = parse(&["--part", "2", "--repeat", "5", "--quiet", "05"]).unwrap();
+
+        assert_eq!(
+            options,
+            RunOptions {
+                part: Some(2),
+                repeat: Some(5),
+                quiet: true,
+                ..RunOptions::default()
+            }
+        );
+        assert_eq!(free, vec![OsString::from("05")]);
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        let (options, _) = parse(&["--input", "-"]).unwrap();
+        assert_eq!(options.input, Some(InputSource::Stdin));
+
+        let (options, _) = parse(&["--stdin"]).unwrap();
+        assert_eq!(options.input, Some(InputSource::Stdin));
+
+        let (options, _) = parse(&["--input", "custom.txt"]).unwrap();
+        assert_eq!(options.input, Some(InputSource::Path("custom.txt".into())));
+    }
+
+    #[test]
+    fn roundtrips_args() {
+        let (options, _) = parse(&[
+            "--stdin", "--part", "1", "--time", "--submit", "1", "--wait",
+        ])
+        .unwrap();
+        let forwarded = options.to_args();
+        let forwarded: Vec<&str> = forwarded.iter().map(String::as_str).collect();
+        assert_eq!(parse(&forwarded).unwrap().0, options);
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--repeat", "0"]).is_err());
+    }
+}
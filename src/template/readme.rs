@@ -0,0 +1,579 @@
+//! Generated sections of the readme, updated with `cargo readme` and by `cargo time --store`.
+//!
+//! A section is enclosed by a pair of markers named after its generator, e.g.
+//!
+//! ```markdown
+//! <!--- aoc:benchmarks columns=part_1,part_2,total --->
+//! ...
+//! <!--- aoc:benchmarks --->
+//! ```
+//!
+//! The opening marker may pass options to the generator. The built-in generators are:
+//! - `progress`: a calendar of the stars of every day.
+//! - `stars`: the number of stars collected.
+//! - `benchmarks`: the benchmark table, `columns` picks its columns from `parse`, `part_1`, `part_2`, `total` and `allocs`.
+//! - `index`: a table of the days with their title, stars, solution and tags.
+//! - `tags`: the days of every tag.
+//!
+//! Tags are read from a `// tags: grid, bfs` comment in a solution.
+//! The legacy `<!--- benchmarking table --->` markers are updated as well.
+
+use std::{collections::HashMap, fs};
+
+use crate::template::answers::Answers;
+use crate::template::backend::get_puzzle_path;
+use crate::template::calendar::{Calendar, Clock, SystemClock};
+use crate::template::readme_benchmarks::{self, Column, TableOptions, get_path_for_bin};
+use crate::template::status::{self, DayStatus};
+use crate::template::submissions::SubmissionLog;
+use crate::template::templates::puzzle_title;
+use crate::template::timings::Timings;
+use crate::template::{Day, Year, all_days, year_path};
+
+const MARKER_START: &str = "<!--- aoc:";
+const MARKER_END: &str = " --->";
+
+/// Everything the generators know about the selected year.
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    pub year: Option<Year>,
+    pub days: Vec<DayStatus>,
+    pub titles: HashMap<Day, String>,
+    pub tags: HashMap<Day, Vec<String>>,
+    pub timings: Timings,
+}
+
+impl Context {
+    /// Read the context of the selected year from its files.
+    pub fn read() -> Result<Self, String> {
+        let calendar = Calendar::selected();
+        let now = SystemClock.now();
+
+        let answers = Answers::read_from_file()?;
+        let log = SubmissionLog::read_from_file()?;
+        let timings = Timings::read_from_file();
+
+        let mut context = Context {
+            year: calendar.map(Calendar::year),
+            timings: timings.clone(),
+            ..Context::default()
+        };
+
+        for day in all_days() {
+            let unlocked = calendar.is_none_or(|calendar| calendar.unlock(day) <= now);
+            context
+                .days
+                .push(DayStatus::read(day, unlocked, &answers, &log, &timings));
+
+            if let Ok(puzzle) = fs::read_to_string(get_puzzle_path(day))
+                && let Some(title) = puzzle_title(&puzzle)
+            {
+                context.titles.insert(day, title);
+            }
+
+            if let Ok(source) = fs::read_to_string(year_path(&format!("src/bin/{day}.rs"))) {
+                let tags = parse_tags(&source);
+                if !tags.is_empty() {
+                    context.tags.insert(day, tags);
+                }
+            }
+        }
+
+        Ok(context)
+    }
+
+    fn stars(&self) -> usize {
+        self.days.iter().map(DayStatus::stars).sum()
+    }
+
+    fn puzzle_link(&self, day: Day) -> String {
+        match self.year {
+            Some(year) => format!(
+                "[Day {}](https://adventofcode.com/{year}/day/{})",
+                day.into_inner(),
+                day.into_inner()
+            ),
+            None => format!("Day {}", day.into_inner()),
+        }
+    }
+}
+
+/// The options of a section, from its opening marker, e.g. `columns=part_1,part_2`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    values: Vec<(String, String)>,
+}
+
+impl Options {
+    fn parse(s: &str) -> Self {
+        let values = s
+            .split_whitespace()
+            .map(|option| match option.split_once('=') {
+                Some((key, value)) => (key.to_string(), value.to_string()),
+                None => (option.to_string(), String::new()),
+            })
+            .collect();
+
+        Self { values }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Fills the sections of one name.
+pub trait Generator {
+    /// The name in the markers of its sections, e.g. `progress` for `<!--- aoc:progress --->`.
+    fn name(&self) -> &'static str;
+
+    /// The content of a section, without its markers. Returns [`None`] to leave the section as it is.
+    fn generate(&self, context: &Context, options: &Options) -> Result<Option<String>, String>;
+}
+
+/// The built-in generators.
+pub fn generators() -> Vec<Box<dyn Generator>> {
+    vec![
+        Box::new(Progress),
+        Box::new(Stars),
+        Box::new(Benchmarks {
+            keep_without_timings: true,
+        }),
+        Box::new(Index),
+        Box::new(Tags),
+    ]
+}
+
+struct Progress;
+
+impl Generator for Progress {
+    fn name(&self) -> &'static str {
+        "progress"
+    }
+
+    fn generate(&self, context: &Context, _: &Options) -> Result<Option<String>, String> {
+        Ok(Some(format!(
+            "```text\n{}```",
+            status::render_grid(&context.days)
+        )))
+    }
+}
+
+struct Stars;
+
+impl Generator for Stars {
+    fn name(&self) -> &'static str {
+        "stars"
+    }
+
+    fn generate(&self, context: &Context, _: &Options) -> Result<Option<String>, String> {
+        Ok(Some(format!(
+            "**⭐ {} / {} stars**",
+            context.stars(),
+            context.days.len() * 2
+        )))
+    }
+}
+
+struct Benchmarks {
+    /// Keep the table if there are no timings, instead of emptying it.
+    keep_without_timings: bool,
+}
+
+impl Generator for Benchmarks {
+    fn name(&self) -> &'static str {
+        "benchmarks"
+    }
+
+    fn generate(&self, context: &Context, options: &Options) -> Result<Option<String>, String> {
+        let mut table = TableOptions::from_env();
+
+        if let Some(columns) = options.get("columns") {
+            let columns = columns
+                .split(',')
+                .map(str::parse::<Column>)
+                .collect::<Result<_, _>>()?;
+            table = table.with_columns(columns);
+        }
+
+        // NOTE: timings are not always stored along with the readme, keep the table of a fresh checkout.
+        if context.timings.data.is_empty() && self.keep_without_timings {
+            return Ok(None);
+        }
+
+        let total_millis = context.timings.total_millis();
+        let lines =
+            readme_benchmarks::benchmark_table("##", context.timings.clone(), total_millis, &table);
+        Ok(Some(lines.join("\n")))
+    }
+}
+
+struct Index;
+
+impl Generator for Index {
+    fn name(&self) -> &'static str {
+        "index"
+    }
+
+    fn generate(&self, context: &Context, _: &Options) -> Result<Option<String>, String> {
+        let mut lines = vec![
+            "| Day | Title | Stars | Solution | Tags |".to_string(),
+            "| :---: | :--- | :---: | :---: | :--- |".to_string(),
+        ];
+
+        for status in context.days.iter().filter(|status| status.solution) {
+            let day = status.day;
+            lines.push(format!(
+                "| {} | {} | {} | [{day}.rs]({}) | {} |",
+                context.puzzle_link(day),
+                context.titles.get(&day).map_or("", String::as_str),
+                "⭐".repeat(status.stars()),
+                get_path_for_bin(day),
+                context
+                    .tags
+                    .get(&day)
+                    .map_or(String::new(), |tags| tags.join(", ")),
+            ));
+        }
+
+        Ok(Some(lines.join("\n")))
+    }
+}
+
+struct Tags;
+
+impl Generator for Tags {
+    fn name(&self) -> &'static str {
+        "tags"
+    }
+
+    fn generate(&self, context: &Context, _: &Options) -> Result<Option<String>, String> {
+        let mut days_by_tag: Vec<(&str, Vec<Day>)> = vec![];
+
+        for status in &context.days {
+            for tag in context.tags.get(&status.day).into_iter().flatten() {
+                match days_by_tag.iter_mut().find(|(t, _)| t == tag) {
+                    Some((_, days)) => days.push(status.day),
+                    None => days_by_tag.push((tag, vec![status.day])),
+                }
+            }
+        }
+
+        days_by_tag.sort_unstable_by_key(|(tag, _)| *tag);
+
+        let mut lines = vec!["| Tag | Days |".to_string(), "| :--- | :--- |".to_string()];
+
+        for (tag, days) in days_by_tag {
+            let days: Vec<String> = days
+                .iter()
+                .map(|day| format!("[{}]({})", day.into_inner(), get_path_for_bin(*day)))
+                .collect();
+            lines.push(format!("| {tag} | {} |", days.join(", ")));
+        }
+
+        Ok(Some(lines.join("\n")))
+    }
+}
+
+/// The tags of a solution, from a `// tags: a, b` comment.
+fn parse_tags(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .find_map(|line| {
+            let comment = line.trim().strip_prefix("//")?.trim();
+            let (key, tags) = comment.split_once(':')?;
+            key.trim().eq_ignore_ascii_case("tags").then_some(tags)
+        })
+        .map(|tags| {
+            tags.split(',')
+                .map(|tag| tag.trim().to_lowercase())
+                .filter(|tag| !tag.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// A marker, e.g. `<!--- aoc:benchmarks columns=total --->`, located in the readme.
+struct Marker<'a> {
+    start: usize,
+    end: usize,
+    name: &'a str,
+    options: &'a str,
+}
+
+fn find_marker(readme: &str, from: usize) -> Result<Option<Marker<'_>>, String> {
+    let Some(start) = readme[from..].find(MARKER_START).map(|i| from + i) else {
+        return Ok(None);
+    };
+
+    let inner_start = start + MARKER_START.len();
+    let inner_end = readme[inner_start..]
+        .find(MARKER_END)
+        .map(|i| inner_start + i)
+        .ok_or_else(|| format!("unterminated marker at byte {start}"))?;
+
+    let inner = readme[inner_start..inner_end].trim();
+    let (name, options) = inner.split_once(' ').unwrap_or((inner, ""));
+
+    Ok(Some(Marker {
+        start,
+        end: inner_end + MARKER_END.len(),
+        name,
+        options,
+    }))
+}
+
+/// Fill the sections of the readme. Returns the number of sections that were filled.
+pub fn update_sections(
+    readme: &mut String,
+    context: &Context,
+    generators: &[Box<dyn Generator>],
+) -> Result<usize, String> {
+    let mut updated = 0;
+    let mut from = 0;
+
+    while let Some(open) = find_marker(readme, from)? {
+        let generator = generators
+            .iter()
+            .find(|generator| generator.name() == open.name)
+            .ok_or_else(|| format!("no generator for section \"{}\"", open.name))?;
+
+        let mut close = find_marker(readme, open.end)?;
+        while let Some(marker) = close.as_ref().filter(|marker| marker.name != open.name) {
+            close = find_marker(readme, marker.end)?;
+        }
+        let close = close.ok_or_else(|| format!("section \"{}\" is not closed", open.name))?;
+
+        let content = generator
+            .generate(context, &Options::parse(open.options))
+            .map_err(|e| format!("section \"{}\": {e}", open.name))?;
+
+        let Some(content) = content else {
+            from = close.end;
+            continue;
+        };
+
+        let section = format!(
+            "{}\n{content}\n{MARKER_START}{}{MARKER_END}",
+            &readme[open.start..open.end],
+            open.name
+        );
+
+        let (start, end) = (open.start, close.end);
+        readme.replace_range(start..end, &section);
+        from = start + section.len();
+        updated += 1;
+    }
+
+    Ok(updated)
+}
+
+/// Keeps the content of a section as it is.
+struct Unchanged(&'static str);
+
+impl Generator for Unchanged {
+    fn name(&self) -> &'static str {
+        self.0
+    }
+
+    fn generate(&self, _: &Context, _: &Options) -> Result<Option<String>, String> {
+        Ok(None)
+    }
+}
+
+/// Empty the benchmark tables of a readme, e.g. once its timings were archived. Other sections keep their content.
+/// Returns the number of tables that were emptied.
+pub fn reset_benchmarks(readme: &mut String) -> Result<usize, String> {
+    let generators: Vec<Box<dyn Generator>> = generators()
+        .iter()
+        .map(|generator| -> Box<dyn Generator> {
+            match generator.name() {
+                "benchmarks" => Box::new(Benchmarks {
+                    keep_without_timings: false,
+                }),
+                name => Box::new(Unchanged(name)),
+            }
+        })
+        .collect();
+
+    let mut reset = update_sections(readme, &Context::default(), &generators)?;
+
+    if readme.contains(readme_benchmarks::MARKER) {
+        readme_benchmarks::update_content(
+            readme,
+            Timings::default(),
+            0.0,
+            TableOptions::from_env(),
+        )
+        .map_err(|e| format!("{e:?}"))?;
+        reset += 1;
+    }
+
+    Ok(reset)
+}
+
+/// Update the generated sections of the selected year's readme.
+pub fn update() -> Result<usize, String> {
+    let path = year_path("README.md");
+    let mut readme = fs::read_to_string(&path).map_err(|e| format!("\"{path}\": {e}"))?;
+    let context = Context::read()?;
+
+    if !readme.contains(MARKER_START) && !readme.contains(readme_benchmarks::MARKER) {
+        return Err(format!(
+            "\"{path}\" has no sections, add a pair of markers such as `{MARKER_START}progress{MARKER_END}`."
+        ));
+    }
+
+    let mut updated = update_sections(&mut readme, &context, &generators())?;
+
+    if readme.contains(readme_benchmarks::MARKER) && !context.timings.data.is_empty() {
+        let timings = context.timings.clone();
+        let total_millis = timings.total_millis();
+        readme_benchmarks::update_content(
+            &mut readme,
+            timings,
+            total_millis,
+            TableOptions::from_env(),
+        )
+        .map_err(|e| format!("{e:?}"))?;
+        updated += 1;
+    }
+
+    fs::write(&path, readme).map_err(|e| format!("\"{path}\": {e}"))?;
+    Ok(updated)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        Context, Generator, Options, generators, parse_tags, reset_benchmarks, update_sections,
+    };
+    use crate::day;
+    use crate::template::Year;
+    use crate::template::status::{DayStatus, FileState, PartStatus};
+
+    fn context() -> Context {
+        let mut context = Context {
+            year: Year::new(2025),
+            ..Context::default()
+        };
+
+        for (day, stars) in [(day!(1), 2), (day!(2), 1)] {
+            context.days.push(DayStatus {
+                day,
+                unlocked: true,
+                solution: true,
+                input: FileState::Present,
+                example: FileState::Present,
+                examples: None,
+                parts: [0, 1].map(|part| PartStatus {
+                    answer: None,
+                    solved: part < stars,
+                    wrong: 0,
+                }),
+                total_nanos: None,
+                timed: false,
+            });
+        }
+
+        context.titles.insert(day!(1), "Secret Entrance".into());
+        context.tags.insert(day!(1), vec!["math".into()]);
+        context
+            .tags
+            .insert(day!(2), vec!["math".into(), "strings".into()]);
+        context
+    }
+
+    #[test]
+    fn fills_sections() {
+        let mut readme = "# AoC\n\
+            <!--- aoc:stars --->\nold\n<!--- aoc:stars --->\n\
+            text\n\
+            <!--- aoc:tags sorted --->\n<!--- aoc:tags --->\n"
+            .to_string();
+
+        let updated = update_sections(&mut readme, &context(), &generators()).unwrap();
+        assert_eq!(updated, 2);
+        assert_eq!(
+            readme,
+            "# AoC\n\
+            <!--- aoc:stars --->\n**⭐ 3 / 4 stars**\n<!--- aoc:stars --->\n\
+            text\n\
+            <!--- aoc:tags sorted --->\n\
+            | Tag | Days |\n\
+            | :--- | :--- |\n\
+            | math | [1](./src/bin/01.rs), [2](./src/bin/02.rs) |\n\
+            | strings | [2](./src/bin/02.rs) |\n\
+            <!--- aoc:tags --->\n"
+        );
+
+        // updating again yields the same readme.
+        let before = readme.clone();
+        update_sections(&mut readme, &context(), &generators()).unwrap();
+        assert_eq!(readme, before);
+    }
+
+    #[test]
+    fn renders_the_index() {
+        let index = generators()
+            .iter()
+            .find(|generator| generator.name() == "index")
+            .unwrap()
+            .generate(&context(), &Options::default())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            index.lines().nth(2),
+            Some(
+                "| [Day 1](https://adventofcode.com/2025/day/1) | Secret Entrance | ⭐⭐ | [01.rs](./src/bin/01.rs) | math |"
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_sections() {
+        let mut unknown = "<!--- aoc:weather --->\n<!--- aoc:weather --->".to_string();
+        assert!(update_sections(&mut unknown, &context(), &generators()).is_err());
+
+        let mut unclosed = "<!--- aoc:stars --->\n".to_string();
+        assert!(update_sections(&mut unclosed, &context(), &generators()).is_err());
+
+        let mut columns =
+            "<!--- aoc:benchmarks columns=parse,nope --->\n<!--- aoc:benchmarks --->".to_string();
+        assert!(update_sections(&mut columns, &context(), &generators()).is_err());
+    }
+
+    #[test]
+    fn resets_benchmarks() {
+        let mut readme = "<!--- aoc:stars --->\nold\n<!--- aoc:stars --->\n\
+            <!--- aoc:benchmarks columns=total --->\n| [Day 1](./src/bin/01.rs) | `1.0ms` |\n<!--- aoc:benchmarks --->\n"
+            .to_string();
+
+        assert_eq!(reset_benchmarks(&mut readme).unwrap(), 1);
+        assert_eq!(
+            readme,
+            "<!--- aoc:stars --->\nold\n<!--- aoc:stars --->\n\
+            <!--- aoc:benchmarks columns=total --->\n\
+            ## Benchmarks\n\n\
+            | Day | Total |\n\
+            | :---: | :---:  |\n\n\
+            **Total: 0.00ms**\n\
+            <!--- aoc:benchmarks --->\n"
+        );
+    }
+
+    #[test]
+    fn parses_tags() {
+        assert_eq!(
+            parse_tags("advent_of_code::solution!(1);\n// Tags: Grid, BFS\n"),
+            vec!["grid", "bfs"]
+        );
+        assert!(parse_tags("// nothing to see").is_empty());
+    }
+}
@@ -1,11 +1,16 @@
-/// Module that updates the readme me with timing information.
+/// Module that renders the benchmark table of the readme, see [`crate::template::readme`].
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{io, str::FromStr};
 
 use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::alloc::AllocStats;
+use crate::template::history::format_nanos;
+use crate::template::timings::{InputAggregate, Timings};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+pub static MARKER: &str = "<!--- benchmarking table --->";
+
+/// Set to `true` to add allocation columns to the benchmark table.
+static ALLOCATIONS_ENV: &str = "AOC_README_ALLOCATIONS";
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -52,75 +57,232 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn format_alloc(alloc: Option<&AllocStats>) -> String {
+    alloc.map_or_else(|| "-".into(), AllocStats::summary)
+}
+
+/// A column of the benchmark table, as named in the `columns` option of a README section,
+/// e.g. `<!--- aoc:benchmarks columns=part_1,part_2,total --->`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    Parse,
+    Part1,
+    Part2,
+    Total,
+    /// The allocations of both parts, in two columns.
+    Allocs,
+}
+
+const DEFAULT_COLUMNS: [Column; 3] = [Column::Parse, Column::Part1, Column::Part2];
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Column::Parse),
+            "part_1" => Ok(Column::Part1),
+            "part_2" => Ok(Column::Part2),
+            "total" => Ok(Column::Total),
+            "allocs" => Ok(Column::Allocs),
+            _ => Err(format!(
+                "unknown column \"{s}\", expected parse, part_1, part_2, total or allocs"
+            )),
+        }
+    }
+}
+
+/// Settings of the benchmark table, read from the environment.
+#[derive(Clone, Debug, Default)]
+pub struct TableOptions {
+    show_allocs: bool,
+    aggregate: InputAggregate,
+    /// The columns after the day. Empty for parse, part 1 and part 2.
+    columns: Vec<Column>,
+}
+
+impl TableOptions {
+    pub fn from_env() -> Self {
+        Self {
+            show_allocs: std::env::var(ALLOCATIONS_ENV).is_ok_and(|v| v == "true"),
+            aggregate: InputAggregate::from_env(),
+            columns: vec![],
+        }
+    }
+
+    pub fn with_columns(self, columns: Vec<Column>) -> Self {
+        Self { columns, ..self }
+    }
+
+    fn columns(&self) -> Vec<Column> {
+        let mut columns = if self.columns.is_empty() {
+            DEFAULT_COLUMNS.to_vec()
+        } else {
+            self.columns.clone()
+        };
+
+        if self.show_allocs && !columns.contains(&Column::Allocs) {
+            columns.push(Column::Allocs);
+        }
+
+        columns
+    }
+}
+
+/// The heading, table and total of the benchmarks, without markers.
+pub fn benchmark_table(
+    prefix: &str,
+    timings: Timings,
+    total_millis: f64,
+    options: &TableOptions,
+) -> Vec<String> {
+    let has_multiple_inputs = timings.data.iter().any(|t| t.inputs.len() > 1);
+    let columns = options.columns();
+
+    let mut headers = vec!["Day"];
+    for column in &columns {
+        match column {
+            Column::Parse => headers.push("Parse"),
+            Column::Part1 => headers.push("Part 1"),
+            Column::Part2 => headers.push("Part 2"),
+            Column::Total => headers.push("Total"),
+            Column::Allocs => headers.extend(["Part 1 Allocs", "Part 2 Allocs"]),
+        }
+    }
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
+        format!("{prefix} Benchmarks"),
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", headers.join(" | ")),
+        format!("| {}  |", vec![":---:"; headers.len()].join(" | ")),
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+        let mut cells = vec![format!(
+            "[Day {}]({})",
             timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            get_path_for_bin(timing.day)
+        )];
+
+        for column in &columns {
+            match column {
+                Column::Parse => cells.push(format_time(timing.parse.as_deref())),
+                Column::Part1 => cells.push(format_time(timing.part_1.as_deref())),
+                Column::Part2 => cells.push(format_time(timing.part_2.as_deref())),
+                Column::Total => cells.push(format!(
+                    "`{}`",
+                    format_nanos(Some(timing.total_nanos as u64))
+                )),
+                Column::Allocs => cells.extend([
+                    format!("`{}`", format_alloc(timing.part_1_alloc.as_ref())),
+                    format!("`{}`", format_alloc(timing.part_2_alloc.as_ref())),
+                ]),
+            }
+        }
+
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+
+    if has_multiple_inputs {
+        lines.push(String::new());
+        lines.push(format!(
+            "_Days with multiple inputs show the {} across their inputs._",
+            options.aggregate.describe()
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
+
+    lines
+}
+
+fn format_time(time: Option<&str>) -> String {
+    format!("`{}`", time.unwrap_or("-"))
+}
+
+fn construct_table(
+    prefix: &str,
+    timings: Timings,
+    total_millis: f64,
+    options: TableOptions,
+) -> String {
+    let mut lines = vec![MARKER.to_string()];
+    lines.extend(benchmark_table(prefix, timings, total_millis, &options));
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+/// Replace the table between the legacy `<!--- benchmarking table --->` markers.
+pub fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    options: TableOptions,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings, total_millis, options);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
-    Ok(())
-}
-
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{Column, MARKER, TableOptions, benchmark_table, update_content};
+    use crate::{
+        day,
+        template::alloc::AllocStats,
+        template::timings::{Timing, Timings},
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    parse_stats: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_alloc: None,
+                    part_1_alloc: Some(AllocStats {
+                        allocations: 3,
+                        bytes: 1536,
+                        peak_bytes: 1024,
+                    }),
+                    part_2_alloc: None,
+                    inputs: vec![],
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
+                    parse: Some("5ms".into()),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    parse_stats: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_alloc: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    inputs: vec![],
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
+                    parse: None,
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    parse_stats: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_alloc: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    inputs: vec![],
                     total_nanos: 9e+10,
                 },
             ],
@@ -131,28 +293,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, TableOptions::default()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, TableOptions::default()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, TableOptions::default()).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, TableOptions::default()).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, TableOptions::default()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,18 +322,18 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, TableOptions::default()).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/04.rs) | `-` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +342,52 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_allocations() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            TableOptions {
+                show_allocs: true,
+                ..TableOptions::default()
+            },
+        )
+        .unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(
+            lines[3],
+            "| Day | Parse | Part 1 | Part 2 | Part 1 Allocs | Part 2 Allocs |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/01.rs) | `-` | `10ms` | `20ms` | `3 allocs, 1.5 KiB, peak 1.0 KiB` | `-` |"
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_with_columns() {
+        let options = TableOptions::default().with_columns(vec![Column::Part2, Column::Total]);
+        let lines = benchmark_table("###", get_mock_timings(), 190.0, &options);
+        assert_eq!(lines[0], "### Benchmarks");
+        assert_eq!(lines[2], "| Day | Part 2 | Total |");
+        assert_eq!(lines[3], "| :---: | :---: | :---:  |");
+        assert_eq!(lines[4], "| [Day 1](./src/bin/01.rs) | `20ms` | `30.0s` |");
+        assert_eq!("totals".parse::<Column>().is_err(), true);
+    }
+
+    #[test]
+    fn notes_multiple_inputs() {
+        let mut timings = get_mock_timings();
+        timings.data[1].inputs = vec!["alice".into(), "bob".into()];
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0, TableOptions::default()).unwrap();
+        assert_eq!(
+            s.contains("_Days with multiple inputs show the worst case across their inputs._"),
+            true
+        );
+    }
 }
@@ -0,0 +1,392 @@
+//! Machine-readable results that solution binaries report back to `run_multi`.
+//!
+//! When the environment variable [`RESULTS_FILE_ENV`] is set, every part is appended to that file
+//! as one JSON object per line. The human-readable output is rendered from the same records.
+
+use std::{collections::HashMap, fmt::Display, fs, io::Write, str::FromStr, time::Duration};
+
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+use crate::template::alloc::AllocStats;
+use crate::template::stats::BenchStats;
+
+/// Environment variable that holds the path of the file records are appended to.
+pub const RESULTS_FILE_ENV: &str = "AOC_RESULTS_FILE";
+
+/// Part number of the records reported for the shared parse stage of a [`crate::template::Solution`].
+pub const PARSE_PART: u8 = 0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned `None`.
+    Unsolved,
+    /// The part was killed for exceeding its time limit.
+    Timeout,
+    /// The part was killed for exceeding its memory limit.
+    OutOfMemory,
+}
+
+impl PartStatus {
+    /// Whether the part was killed for exceeding one of its limits.
+    pub fn is_killed(self) -> bool {
+        matches!(self, PartStatus::Timeout | PartStatus::OutOfMemory)
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Timeout => "timeout",
+            PartStatus::OutOfMemory => "oom",
+        })
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "timeout" => Ok(PartStatus::Timeout),
+            "oom" => Ok(PartStatus::OutOfMemory),
+            s => Err(format!("Unknown part status `{s}`.")),
+        }
+    }
+}
+
+/// The outcome of running a single part of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRecord {
+    pub day: Day,
+    pub part: u8,
+    /// Label of the input the part ran against, if the day has multiple inputs.
+    pub input: Option<String>,
+    pub answer: Option<String>,
+    pub stats: BenchStats,
+    /// Allocations of the first run, if they were counted.
+    pub alloc: Option<AllocStats>,
+    pub status: PartStatus,
+    /// The raw samples of a benchmark, only reported with `--samples`.
+    pub samples: Vec<Duration>,
+}
+
+impl PartRecord {
+    pub fn new(day: Day, part: u8, answer: Option<String>, stats: BenchStats) -> Self {
+        let status = if answer.is_some() {
+            PartStatus::Solved
+        } else {
+            PartStatus::Unsolved
+        };
+
+        Self {
+            day,
+            part,
+            input: None,
+            answer,
+            stats,
+            alloc: None,
+            status,
+            samples: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn with_input(mut self, input: Option<String>) -> Self {
+        self.input = input;
+        self
+    }
+
+    #[must_use]
+    pub fn with_alloc(mut self, alloc: Option<AllocStats>) -> Self {
+        self.alloc = alloc;
+        self
+    }
+
+    #[must_use]
+    pub fn with_samples(mut self, samples: Vec<Duration>) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Creates the record of a parse stage, which has no answer.
+    pub fn parse(day: Day, stats: BenchStats) -> Self {
+        Self {
+            day,
+            part: PARSE_PART,
+            input: None,
+            answer: None,
+            stats,
+            alloc: None,
+            status: PartStatus::Solved,
+            samples: vec![],
+        }
+    }
+
+    /// Creates the record of a part that was killed after running for `elapsed`.
+    pub fn killed(day: Day, part: u8, status: PartStatus, elapsed: Duration) -> Self {
+        Self {
+            day,
+            part,
+            input: None,
+            answer: None,
+            stats: BenchStats::single(elapsed),
+            alloc: None,
+            status,
+            samples: vec![],
+        }
+    }
+
+    pub fn is_parse(&self) -> bool {
+        self.part == PARSE_PART
+    }
+
+    /// Whether this record holds the result of a benchmark, rather than a single run.
+    pub fn is_benched(&self) -> bool {
+        self.status == PartStatus::Solved && self.stats.samples > 1
+    }
+
+    /// Append the record to the results file, if one was requested by the parent process.
+    pub fn emit(&self) {
+        let Ok(path) = std::env::var(RESULTS_FILE_ENV) else {
+            return;
+        };
+
+        let line = match JsonValue::from(self).stringify() {
+            Ok(line) => line,
+            Err(e) => {
+                eprintln!("Failed to serialize result record: {e}");
+                return;
+            }
+        };
+
+        let res = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| writeln!(file, "{line}"));
+
+        if let Err(e) = res {
+            eprintln!("Failed to write result record to \"{path}\": {e}");
+        }
+    }
+}
+
+/// Parse all records of a results file, skipping blank lines.
+pub fn parse_records(content: &str) -> Result<Vec<PartRecord>, String> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err("Result record is not valid JSON."))?;
+            PartRecord::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartRecord> for JsonValue {
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "input".into(),
+            value
+                .input
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.stats.median.as_nanos() as f64),
+        );
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+        map.insert(
+            "alloc".into(),
+            value
+                .alloc
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+
+        if !value.samples.is_empty() {
+            map.insert(
+                "raw_samples".into(),
+                JsonValue::Array(
+                    value
+                        .samples
+                        .iter()
+                        .map(|sample| JsonValue::Number(sample.as_nanos() as f64))
+                        .collect(),
+                ),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartRecord {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("Expected record.part to be a number.")?;
+
+        let input = json.get("input").and_then(|v| v.get::<String>()).cloned();
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.status to be a string.")?
+            .parse()?;
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected record.stats to be present.")
+            .map(BenchStats::try_from)??;
+
+        let alloc = json
+            .get("alloc")
+            .filter(|v| !v.is_null())
+            .map(AllocStats::try_from)
+            .transpose()?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = match json.get("raw_samples") {
+            Some(samples) => samples
+                .get::<Vec<JsonValue>>()
+                .ok_or("Expected record.raw_samples to be an array.")?
+                .iter()
+                .map(|sample| {
+                    sample
+                        .get::<f64>()
+                        .map(|nanos| Duration::from_nanos(*nanos as u64))
+                        .ok_or("Expected record.raw_samples to hold numbers.")
+                })
+                .collect::<Result<_, _>>()?,
+            None => vec![],
+        };
+
+        Ok(PartRecord {
+            day,
+            part,
+            input,
+            answer: answer.cloned(),
+            stats,
+            alloc,
+            status,
+            samples,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{PartRecord, PartStatus, parse_records};
+    use crate::{
+        day,
+        template::{alloc::AllocStats, stats::BenchStats},
+    };
+
+    #[test]
+    fn roundtrips_records() {
+        let record = PartRecord::new(
+            day!(3),
+            2,
+            Some("a \"tricky\" (answer @ 5 samples)\nwith newline".into()),
+            BenchStats::from_samples(&[Duration::from_nanos(100), Duration::from_nanos(120)])
+                .unwrap(),
+        )
+        .with_input(Some("alice".into()))
+        .with_alloc(Some(AllocStats {
+            allocations: 3,
+            bytes: 2048,
+            peak_bytes: 1024,
+        }))
+        .with_samples(vec![Duration::from_nanos(100), Duration::from_nanos(120)]);
+
+        let line = tinyjson::JsonValue::from(&record).stringify().unwrap();
+        assert_eq!(line.contains('\n'), false);
+
+        let parsed = parse_records(&format!("{line}\n\n{line}\n")).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0], record);
+    }
+
+    #[test]
+    fn roundtrips_parse_records() {
+        let record = PartRecord::parse(day!(5), BenchStats::single(Duration::from_nanos(10)));
+        let line = tinyjson::JsonValue::from(&record).stringify().unwrap();
+        let parsed = parse_records(&line).unwrap();
+        assert_eq!(parsed[0].is_parse(), true);
+        assert_eq!(parsed[0].status, PartStatus::Solved);
+        assert_eq!(parsed[0].answer, None);
+    }
+
+    #[test]
+    fn marks_missing_answers_as_unsolved() {
+        let record = PartRecord::new(day!(1), 1, None, BenchStats::single(Duration::ZERO));
+        assert_eq!(record.status, PartStatus::Unsolved);
+        assert_eq!(record.is_benched(), false);
+    }
+
+    #[test]
+    fn roundtrips_killed_records() {
+        let record = PartRecord::killed(day!(9), 2, PartStatus::Timeout, Duration::from_secs(1));
+        let line = tinyjson::JsonValue::from(&record).stringify().unwrap();
+        let parsed = parse_records(&line).unwrap();
+        assert_eq!(parsed[0], record);
+        assert_eq!(parsed[0].status.is_killed(), true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_records() {
+        parse_records(r#"{ "day": "01", "part": 1 }"#).unwrap();
+    }
+}
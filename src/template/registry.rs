@@ -0,0 +1,253 @@
+//! A registry of solutions that allows running many days within a single process.
+//!
+//! Every `solution!` invocation generates a `register` function for its day. The `solutions` binary
+//! includes all day modules found in `src/bin` (see `build.rs`) and registers them here.
+
+use std::{collections::BTreeMap, fs, panic, process, time::Instant};
+
+use crate::template::manifest::{self, Manifest};
+use crate::template::options::{self, RunOptions};
+use crate::template::record::PartRecord;
+use crate::template::runner;
+use crate::template::scale::{self, ScaleOptions};
+use crate::template::stats::BenchStats;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, all_days};
+
+/// Runs a solution against an input, printing and reporting the result of each part.
+pub type SolutionFn = fn(&str);
+
+/// Solves a single part of a solution silently and returns its answer.
+pub type SolverFn = fn(&str, u8) -> Option<String>;
+
+/// Generates a synthetic input of the given size, e.g. the number of lines.
+pub type GeneratorFn = fn(usize) -> String;
+
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<Day, SolutionFn>,
+    solvers: BTreeMap<Day, SolverFn>,
+    generators: BTreeMap<Day, GeneratorFn>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a day's solution.
+    pub fn register(&mut self, day: Day, solution: SolutionFn) {
+        self.days.insert(day, solution);
+    }
+
+    pub fn register_solver(&mut self, day: Day, solver: SolverFn) {
+        self.solvers.insert(day, solver);
+    }
+
+    /// Register the input generator of a day, see `cargo scale`.
+    pub fn register_generator(&mut self, day: Day, generator: GeneratorFn) {
+        self.generators.insert(day, generator);
+    }
+
+    pub fn solver(&self, day: Day) -> Option<SolverFn> {
+        self.solvers.get(&day).copied()
+    }
+
+    pub fn generator(&self, day: Day) -> Option<GeneratorFn> {
+        self.generators.get(&day).copied()
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.days.contains_key(&day)
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = Day> + '_ {
+        self.days.keys().copied()
+    }
+
+    /// Run a day's solution against its inputs, or the input passed with `--input`. Returns `false`
+    /// if the day is not registered, its inputs could not be read, or the solution panicked.
+    pub fn run_day(&self, day: Day) -> bool {
+        let Some(solution) = self.days.get(&day) else {
+            return false;
+        };
+
+        match &options::current().input {
+            Some(source) => runner::run_source(source, solution),
+            None => runner::run_inputs(day, solution),
+        }
+    }
+
+    /// Entry point of the aggregate binary. Runs the days passed as arguments (or all registered days) in order.
+    ///
+    /// With `--part N`, only that part is run and no day headers are printed. This is used by `run_multi`
+    /// to run the remaining part of a day in a fresh process after the other part was killed.
+    ///
+    /// With `--scale`, the days are benchmarked against generated inputs of growing size instead.
+    ///
+    /// With `--examples <manifest>`, the examples of a manifest are solved instead, see `cargo test-day`.
+    pub fn run_from_args(&self) {
+        let mut args = pico_args::Arguments::from_env();
+
+        // NOTE: these options are read by the runner, consume them so they are not mistaken for days.
+        let part = RunOptions::from_args(&mut args)
+            .unwrap_or_else(|e| {
+                eprintln!("Invalid arguments: {e}");
+                process::exit(1);
+            })
+            .part;
+
+        let scale_options = args
+            .contains("--scale")
+            .then(|| ScaleOptions::from_args(&mut args))
+            .transpose()
+            .unwrap_or_else(|e| {
+                eprintln!("Invalid scale option: {e}");
+                process::exit(1);
+            });
+
+        // NOTE: the year was selected before the registry was built, see `solutions.rs`.
+        let _: Option<Year> = args.opt_value_from_str("--year").unwrap_or_else(|e| {
+            eprintln!("Invalid year: {e}");
+            process::exit(1);
+        });
+
+        let examples_path: Option<String> =
+            args.opt_value_from_str("--examples").unwrap_or_else(|e| {
+                eprintln!("Invalid examples option: {e}");
+                process::exit(1);
+            });
+
+        let mut days = vec![];
+
+        for arg in args.finish() {
+            let arg = arg.to_string_lossy();
+            match arg.parse::<Day>() {
+                Ok(day) => days.push(day),
+                Err(e) => {
+                    eprintln!("Invalid day `{arg}`: {e}");
+                    process::exit(1);
+                }
+            }
+        }
+
+        if days.is_empty() {
+            days = self.days().collect();
+        }
+
+        if let Some(path) = examples_path {
+            for day in all_days().filter(|day| days.contains(day)) {
+                self.solve_examples(day, &path);
+            }
+            return;
+        }
+
+        if let Some(options) = scale_options {
+            for day in all_days().filter(|day| days.contains(day)) {
+                self.scale_day(day, part, &options);
+            }
+            return;
+        }
+
+        let mut need_space = false;
+
+        // NOTE: use non-duplicate, sorted day values.
+        for day in all_days().filter(|day| days.contains(day)) {
+            if part.is_none() {
+                if need_space {
+                    println!();
+                }
+                need_space = true;
+
+                println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+                println!("------");
+            }
+
+            if !self.contains(day) {
+                println!("Not solved.");
+                continue;
+            }
+
+            self.run_day(day);
+        }
+    }
+
+    /// Solve every example of a manifest and report one record per example, labelled with its index.
+    fn solve_examples(&self, day: Day, path: &str) {
+        let manifest = fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| Manifest::parse(day, &content))
+            .unwrap_or_else(|e| {
+                eprintln!("Invalid examples manifest \"{path}\": {e}");
+                process::exit(1);
+            });
+
+        let Some(solver) = self.solver(day) else {
+            return;
+        };
+
+        for (index, example) in manifest.examples.iter().enumerate() {
+            let timer = Instant::now();
+
+            // NOTE: a panicking example is not reported, but should not prevent the remaining ones from being solved.
+            let Ok(answer) = panic::catch_unwind(|| {
+                manifest::solve_example(|input| solver(input, example.part), example)
+            }) else {
+                continue;
+            };
+
+            PartRecord::new(
+                day,
+                example.part,
+                answer,
+                BenchStats::single(timer.elapsed()),
+            )
+            .with_input(Some(index.to_string()))
+            .emit();
+        }
+    }
+
+    /// Benchmark the parts of a day against generated inputs of growing size.
+    fn scale_day(&self, day: Day, part: Option<u8>, options: &ScaleOptions) {
+        let (Some(solver), Some(generator)) = (self.solver(day), self.generator(day)) else {
+            eprintln!(
+                "Day {day} has no input generator. Register one with `solution!({}, generator = generate)`.",
+                day.into_inner()
+            );
+            return;
+        };
+
+        for part in [1, 2]
+            .into_iter()
+            .filter(|p| part.is_none_or(|part| part == *p))
+        {
+            let points = scale::measure(|input| solver(input, part), generator, options);
+            scale::print_report(day, part, &points);
+            println!();
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Registry;
+    use crate::day;
+
+    #[test]
+    fn registers_days() {
+        let mut registry = Registry::new();
+        registry.register(day!(3), |_| {});
+        registry.register(day!(1), |_| {});
+
+        assert_eq!(registry.contains(day!(1)), true);
+        assert_eq!(registry.contains(day!(2)), false);
+        assert_eq!(registry.days().collect::<Vec<_>>(), vec![day!(1), day!(3)]);
+    }
+
+    #[test]
+    fn skips_unregistered_days() {
+        let registry = Registry::new();
+        assert_eq!(registry.run_day(day!(1)), false);
+    }
+}
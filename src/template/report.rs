@@ -0,0 +1,410 @@
+//! A self-contained HTML report of a benchmark run, see `cargo time --report`.
+//!
+//! The report shows a bar chart per day that puts the medians of its parts next to the stored timings, a
+//! histogram of the raw samples of every benched part, and a table of the changes against the stored timings.
+//! Charts are inline SVG, so reports can be opened offline and shared as a single file.
+
+use std::{fmt::Write, fs, io, time::Duration};
+
+use crate::template::compare::{self, Change, Thresholds, measurement};
+use crate::template::history::format_timestamp;
+use crate::template::record::PartRecord;
+use crate::template::timings::Timings;
+use crate::template::{Year, year_path};
+
+pub const REPORTS_DIR: &str = "data/reports";
+
+const CHART_WIDTH: f64 = 640.0;
+/// Width reserved for the labels left of the bars, and the values right of them.
+const LABEL_WIDTH: f64 = 70.0;
+const VALUE_WIDTH: f64 = 80.0;
+const BAR_HEIGHT: f64 = 14.0;
+
+const HISTOGRAM_HEIGHT: f64 = 120.0;
+const HISTOGRAM_BINS: usize = 24;
+
+const STYLE: &str =
+    "body { background: #0f0f23; color: #cccccc; font-family: monospace; margin: 2em; }
+h1, h2 { color: #00cc00; }
+section { margin-bottom: 2em; }
+svg text { fill: #cccccc; font-family: monospace; font-size: 11px; }
+.current { fill: #ffff66; }
+.previous { fill: #666666; }
+.median { stroke: #00cc00; stroke-width: 2; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 1em; text-align: right; }
+.faster { color: #00cc00; }
+.slower { color: #ff6666; }
+.regression { color: #ff0000; font-weight: bold; }";
+
+/// The results of a run of `cargo time`, and what they are compared against.
+pub struct Report<'a> {
+    pub year: Option<Year>,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub timings: &'a Timings,
+    /// The records of all parts, with their raw samples.
+    pub records: &'a [PartRecord],
+    /// The stored timings of the days before the run.
+    pub previous: &'a Timings,
+    pub thresholds: Thresholds,
+}
+
+impl Report<'_> {
+    /// Render the report to a HTML document.
+    pub fn render(&self) -> String {
+        let title = match self.year {
+            Some(year) => format!("Advent of Code {year} benchmarks"),
+            None => "Advent of Code benchmarks".into(),
+        };
+
+        let mut out = String::new();
+        out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        let _ = writeln!(out, "<title>{title}</title>");
+        let _ = writeln!(out, "<style>\n{STYLE}\n</style>\n</head>\n<body>");
+        let _ = writeln!(out, "<h1>{title}</h1>");
+        let _ = writeln!(
+            out,
+            "<p>Run at {} UTC, total {:.2}ms.</p>",
+            format_timestamp(self.timestamp),
+            self.timings.total_millis()
+        );
+
+        out.push_str(&self.comparison_table());
+
+        for timing in &self.timings.data {
+            let _ = writeln!(out, "<section>\n<h2>Day {}</h2>", timing.day);
+
+            let previous = self.previous.data.iter().find(|t| t.day == timing.day);
+            let bars: Vec<Bar> = (0..=2)
+                .filter_map(|part| {
+                    Some(Bar {
+                        label: part_label(part),
+                        current: measurement(timing, part)?.0,
+                        previous: previous.and_then(|t| measurement(t, part)).map(|m| m.0),
+                    })
+                })
+                .collect();
+            out.push_str(&bar_chart(&bars));
+
+            for record in self.records.iter().filter(|r| r.day == timing.day) {
+                if record.samples.len() > 1 {
+                    out.push_str(&histogram_chart(record));
+                }
+            }
+
+            out.push_str("</section>\n");
+        }
+
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    /// Write the report to `data/reports`, named after the time of the run. Returns the path of the report.
+    pub fn write(&self) -> Result<String, io::Error> {
+        let dir = year_path(REPORTS_DIR);
+        fs::create_dir_all(&dir)?;
+
+        let path = format!("{dir}/{}.html", file_stem(self.timestamp));
+        fs::write(&path, self.render())?;
+        Ok(path)
+    }
+
+    fn comparison_table(&self) -> String {
+        let comparisons = compare::compare(self.previous, self.timings, &self.thresholds);
+        if comparisons.is_empty() {
+            return "<p>No stored timings to compare against.</p>\n".into();
+        }
+
+        let mut out = format!(
+            "<h2>Comparison with stored timings</h2>\n<p>Noise ±{}%, limit +{}%.</p>\n<table>\n\
+             <tr><th>Day</th><th>Part</th><th>Stored</th><th>Current</th><th>Change</th><th></th></tr>\n",
+            self.thresholds.noise, self.thresholds.max_regression
+        );
+
+        for comparison in comparisons {
+            let (class, verdict) = match comparison.change {
+                Change::Failed => ("regression", "failed"),
+                _ if comparison.is_regression(&self.thresholds) => ("regression", "regression"),
+                Change::Faster => ("faster", "faster"),
+                Change::Noise => ("noise", "~ noise"),
+                Change::Slower => ("slower", "slower"),
+            };
+
+            let (current, percent) = match comparison.current {
+                Some(current) => (
+                    format!("{current:.1?}"),
+                    format!("{:+.1}%", comparison.percent),
+                ),
+                None => ("-".into(), "-".into()),
+            };
+
+            let _ = writeln!(
+                out,
+                "<tr class=\"{class}\"><td>{}</td><td>{}</td><td>{:.1?}</td><td>{current}</td><td>{percent}</td><td>{verdict}</td></tr>",
+                comparison.day,
+                part_label(comparison.part),
+                comparison.baseline,
+            );
+        }
+
+        out.push_str("</table>\n");
+        out
+    }
+}
+
+/// The file name of a report, e.g. `2025-12-05-14-03-00` for a run at `2025-12-05 14:03:00`.
+/// Names go down to the second, so that consecutive runs do not overwrite each other's reports.
+fn file_stem(timestamp: u64) -> String {
+    format!(
+        "{}-{:02}",
+        format_timestamp(timestamp).replace([' ', ':'], "-"),
+        timestamp % 60
+    )
+}
+
+fn part_label(part: u8) -> String {
+    match part {
+        0 => "Parse".into(),
+        part => format!("Part {part}"),
+    }
+}
+
+/// The median of a part in the current run, and in the stored timings if it was stored.
+struct Bar {
+    label: String,
+    current: Duration,
+    previous: Option<Duration>,
+}
+
+/// A horizontal bar chart with a pair of bars per part, scaled to the slowest bar.
+fn bar_chart(bars: &[Bar]) -> String {
+    let max = bars
+        .iter()
+        .flat_map(|bar| [Some(bar.current), bar.previous])
+        .flatten()
+        .max()
+        .unwrap_or_default()
+        .as_secs_f64();
+
+    let row_height = BAR_HEIGHT * 2.0 + 10.0;
+    let height = row_height * bars.len() as f64;
+    let scale = |duration: Duration| {
+        if max > 0.0 {
+            duration.as_secs_f64() / max * (CHART_WIDTH - LABEL_WIDTH - VALUE_WIDTH)
+        } else {
+            0.0
+        }
+    };
+
+    let mut out = format!(
+        "<svg width=\"{CHART_WIDTH}\" height=\"{height}\" viewBox=\"0 0 {CHART_WIDTH} {height}\" role=\"img\">\n"
+    );
+
+    for (index, bar) in bars.iter().enumerate() {
+        let y = index as f64 * row_height;
+        let _ = writeln!(
+            out,
+            "<text x=\"0\" y=\"{:.1}\">{}</text>",
+            y + BAR_HEIGHT,
+            bar.label
+        );
+
+        let series = [("current", Some(bar.current)), ("previous", bar.previous)];
+        for (offset, (class, duration)) in series.into_iter().enumerate() {
+            let Some(duration) = duration else {
+                continue;
+            };
+
+            let y = y + offset as f64 * BAR_HEIGHT;
+            let width = scale(duration);
+            let _ = writeln!(
+                out,
+                "<rect class=\"{class}\" x=\"{LABEL_WIDTH}\" y=\"{y:.1}\" width=\"{width:.1}\" height=\"{:.1}\"><title>{class}: {duration:.1?}</title></rect>",
+                BAR_HEIGHT - 2.0
+            );
+            let _ = writeln!(
+                out,
+                "<text x=\"{:.1}\" y=\"{:.1}\">{duration:.1?}</text>",
+                LABEL_WIDTH + width + 4.0,
+                y + BAR_HEIGHT - 4.0
+            );
+        }
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+/// Count the samples in `bins` equal bins from the fastest sample to the 99th percentile.
+/// The slowest percent of samples is counted in the last bin, so that outliers do not flatten the histogram.
+/// Returns the lower and upper bound of the bins, and the count of each bin.
+fn histogram(samples: &[Duration], bins: usize) -> (Duration, Duration, Vec<usize>) {
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+
+    let low = sorted[0];
+    let high = sorted[(sorted.len() - 1) * 99 / 100];
+    let width = (high - low).as_secs_f64() / bins as f64;
+
+    let mut counts = vec![0; bins];
+    for sample in sorted {
+        let bin = if width > 0.0 {
+            ((sample.saturating_sub(low)).as_secs_f64() / width) as usize
+        } else {
+            0
+        };
+        counts[bin.min(bins - 1)] += 1;
+    }
+
+    (low, high, counts)
+}
+
+fn histogram_chart(record: &PartRecord) -> String {
+    let (low, high, counts) = histogram(&record.samples, HISTOGRAM_BINS);
+    let max = counts.iter().copied().max().unwrap_or(1).max(1) as f64;
+
+    let plot_width = CHART_WIDTH - LABEL_WIDTH;
+    let bin_width = plot_width / HISTOGRAM_BINS as f64;
+    let height = HISTOGRAM_HEIGHT + 30.0;
+
+    let mut label = part_label(record.part);
+    if let Some(input) = &record.input {
+        label = format!("{label} ({})", escape(input));
+    }
+
+    let mut out = format!(
+        "<svg width=\"{CHART_WIDTH}\" height=\"{height}\" viewBox=\"0 0 {CHART_WIDTH} {height}\" role=\"img\">\n\
+         <text x=\"0\" y=\"14\">{label}</text>\n\
+         <text x=\"0\" y=\"30\">{} samples</text>\n",
+        record.samples.len()
+    );
+
+    for (index, count) in counts.iter().enumerate() {
+        let bar_height = *count as f64 / max * HISTOGRAM_HEIGHT;
+        let _ = writeln!(
+            out,
+            "<rect class=\"current\" x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{bar_height:.1}\"><title>{count}</title></rect>",
+            LABEL_WIDTH + index as f64 * bin_width,
+            HISTOGRAM_HEIGHT - bar_height,
+            bin_width - 1.0,
+        );
+    }
+
+    let range = (high - low).as_secs_f64();
+    if range > 0.0 {
+        let median = record.stats.median.clamp(low, high);
+        let x = LABEL_WIDTH + (median - low).as_secs_f64() / range * plot_width;
+        let _ = writeln!(
+            out,
+            "<line class=\"median\" x1=\"{x:.1}\" y1=\"0\" x2=\"{x:.1}\" y2=\"{HISTOGRAM_HEIGHT}\"><title>median: {:.1?}</title></line>",
+            record.stats.median
+        );
+    }
+
+    let _ = write!(
+        out,
+        "<text x=\"{LABEL_WIDTH}\" y=\"{:.1}\">{low:.1?}</text>\n\
+         <text x=\"{CHART_WIDTH}\" y=\"{:.1}\" text-anchor=\"end\">{high:.1?} (p99)</text>\n</svg>\n",
+        HISTOGRAM_HEIGHT + 16.0,
+        HISTOGRAM_HEIGHT + 16.0,
+    );
+
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Report, file_stem, histogram};
+    use crate::day;
+    use crate::template::compare::Thresholds;
+    use crate::template::record::PartRecord;
+    use crate::template::stats::BenchStats;
+    use crate::template::timings::{InputAggregate, Timing, Timings};
+
+    fn record(part: u8, nanos: &[u64]) -> PartRecord {
+        let samples: Vec<Duration> = nanos.iter().copied().map(Duration::from_nanos).collect();
+        PartRecord::new(
+            day!(1),
+            part,
+            Some("42".into()),
+            BenchStats::from_samples(&samples).unwrap(),
+        )
+        .with_samples(samples)
+    }
+
+    #[test]
+    fn bins_samples_up_to_the_99th_percentile() {
+        let mut samples: Vec<Duration> = (0..100).map(|n| Duration::from_nanos(100 + n)).collect();
+        samples.push(Duration::from_millis(1));
+
+        let (low, high, counts) = histogram(&samples, 10);
+
+        assert_eq!(low, Duration::from_nanos(100));
+        assert_eq!(high, Duration::from_nanos(199));
+        assert_eq!(counts.len(), 10);
+        assert_eq!(counts.iter().sum::<usize>(), 101);
+        assert_eq!(counts[0], 10);
+        assert_eq!(counts[9], 11);
+
+        let (_, _, counts) = histogram(&[Duration::from_nanos(5); 3], 4);
+        assert_eq!(counts, vec![3, 0, 0, 0]);
+    }
+
+    #[test]
+    fn renders_charts_and_comparison() {
+        let records = [record(1, &[100, 110, 120, 130]), record(2, &[1000, 1200])];
+        let previous_records = [record(1, &[200, 210, 220, 230])];
+
+        let timings = Timings {
+            data: vec![Timing::from_records(
+                day!(1),
+                &records,
+                InputAggregate::Worst,
+            )],
+        };
+        let previous = Timings {
+            data: vec![Timing::from_records(
+                day!(1),
+                &previous_records,
+                InputAggregate::Worst,
+            )],
+        };
+
+        let report = Report {
+            year: None,
+            timestamp: 1_764_943_380,
+            timings: &timings,
+            records: &records,
+            previous: &previous,
+            thresholds: Thresholds::default(),
+        }
+        .render();
+
+        assert!(report.starts_with("<!DOCTYPE html>"));
+        assert!(report.contains("<h2>Day 01</h2>"));
+        assert_eq!(report.matches("<svg").count(), 3);
+        assert_eq!(report.matches("class=\"previous\"").count(), 1);
+        assert!(report.contains(
+            "<td>Part 1</td><td>215.0ns</td><td>115.0ns</td><td>-46.5%</td><td>faster</td>"
+        ));
+        assert!(!report.contains("<td>Part 2</td>"));
+    }
+
+    #[test]
+    fn names_reports_after_the_run() {
+        assert_eq!(file_stem(1_764_943_380), "2025-12-05-14-03-00");
+        assert_eq!(file_stem(1_764_943_421), "2025-12-05-14-03-41");
+    }
+}
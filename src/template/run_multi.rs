@@ -1,38 +1,62 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    env,
+    fs::{self, File},
+    io,
+    path::Path,
+    process,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
 
 use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    limits::LimitsConfig,
+    record::PartRecord,
+    timings::{InputAggregate, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
-
-    let mut need_space = false;
+/// Whether timed children report the raw samples of their benchmarks.
+static REPORT_SAMPLES: AtomicBool = AtomicBool::new(false);
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+/// Have timed children report the raw samples of their benchmarks, e.g. for `cargo time --report`.
+pub fn report_samples() {
+    REPORT_SAMPLES.store(true, Ordering::Relaxed);
+}
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    limits: &LimitsConfig,
+    jobs: usize,
+) -> Option<Timings> {
+    run_multi_records(days_to_run, is_release, is_timed, limits, jobs).0
+}
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+/// Like [`run_multi`], but also returns the records of all parts.
+pub fn run_multi_records(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    limits: &LimitsConfig,
+    jobs: usize,
+) -> (Option<Timings>, Vec<PartRecord>) {
+    // NOTE: use non-duplicate, sorted day values.
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+    let records = run_records(&days, is_release, is_timed, limits, jobs);
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    let aggregate = InputAggregate::from_env();
+    let timings: Vec<Timing> = days
+        .iter()
+        .filter(|day| records.iter().any(|r| r.day == **day))
+        .map(|day| Timing::from_records(*day, &records, aggregate))
+        .collect();
 
     if is_timed {
         let timings = Timings { data: timings };
@@ -40,17 +64,147 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
+        (Some(timings), records)
     } else {
-        None
+        (None, records)
     }
 }
 
+/// Build the solutions and run the given days, printing their output. Returns the records of all parts.
+pub fn run_records(
+    days: &[Day],
+    is_release: bool,
+    is_timed: bool,
+    limits: &LimitsConfig,
+    jobs: usize,
+) -> Vec<PartRecord> {
+    let bin = match child_commands::build_solutions(is_release) {
+        Ok(bin) => bin,
+        Err(e) => {
+            eprintln!("Failed to build solutions: {e:?}");
+            process::exit(1);
+        }
+    };
+
+    if jobs > 1 {
+        run_parallel(&bin, days, is_timed, limits, jobs)
+    } else if limits.is_empty() {
+        child_commands::run_solutions(&bin, days, is_timed, None).unwrap_or_else(|e| {
+            eprintln!("Failed to run solutions: {e:?}");
+            process::exit(1);
+        })
+    } else {
+        run_supervised(&bin, days, is_timed, limits)
+    }
+}
+
+/// Run every day in its own process, so that a part exceeding its limits can be killed without affecting the other days.
+fn run_supervised(
+    bin: &Path,
+    days: &[Day],
+    is_timed: bool,
+    limits: &LimitsConfig,
+) -> Vec<PartRecord> {
+    let mut records = vec![];
+
+    for (index, &day) in days.iter().enumerate() {
+        // the child prints the day header itself.
+        if index > 0 {
+            println!();
+        }
+
+        records.extend(child_commands::run_day(bin, day, is_timed, limits, None).unwrap());
+    }
+
+    records
+}
+
+/// Run up to `jobs` days concurrently, each in its own process. The output of every day is buffered
+/// and printed in day order once all preceding days have finished.
+fn run_parallel(
+    bin: &Path,
+    days: &[Day],
+    is_timed: bool,
+    limits: &LimitsConfig,
+    jobs: usize,
+) -> Vec<PartRecord> {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    let mut records = vec![];
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let tx = tx.clone();
+            let next = &next;
+
+            scope.spawn(move || {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&day) = days.get(index) else {
+                        break;
+                    };
+
+                    let result = run_buffered(bin, day, is_timed, limits)
+                        .unwrap_or_else(|e| (format!("Failed to run day {day}: {e:?}\n"), vec![]));
+
+                    if tx.send((index, result)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        drop(tx);
+
+        let mut finished = BTreeMap::new();
+        let mut next_index = 0;
+
+        for (index, result) in rx {
+            finished.insert(index, result);
+
+            while let Some((output, day_records)) = finished.remove(&next_index) {
+                if next_index > 0 {
+                    println!();
+                }
+                print!("{output}");
+                records.extend(day_records);
+                next_index += 1;
+            }
+        }
+    });
+
+    records
+}
+
+/// Run a single day with its output written to a temporary file, and return that output along with the day's records.
+fn run_buffered(
+    bin: &Path,
+    day: Day,
+    is_timed: bool,
+    limits: &LimitsConfig,
+) -> Result<(String, Vec<PartRecord>), Error> {
+    let output_path = env::temp_dir().join(format!("aoc-output-{}-{day}.txt", process::id()));
+    let output = File::create(&output_path)?;
+
+    // the child prints the day header itself.
+    let records = if limits.is_empty() {
+        child_commands::run_solutions(bin, &[day], is_timed, Some(&output))?
+    } else {
+        child_commands::run_day(bin, day, is_timed, limits, Some(&output))?
+    };
+
+    let content = fs::read_to_string(&output_path)?;
+    let _ = fs::remove_file(&output_path);
+    Ok((content, records))
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
-    BrokenPipe,
+    BuildFailed,
+    RunFailed,
     IO(io::Error),
+    Parser(String),
 }
 
 impl From<std::io::Error> for Error {
@@ -59,199 +213,550 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// All solutions are linked into the aggregate `solutions` binary, which runs a set of days in-process.
+/// This module encapsulates interaction with that binary, both invoking it as well as collecting the results it reports.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, REPORT_SAMPLES};
+    use crate::template::{
+        Day, Year, inputs,
+        limits::{Limits, LimitsConfig},
+        record::{PartRecord, PartStatus, RESULTS_FILE_ENV, parse_records},
+        runner::{bench_time_limit, format_killed, print_record},
+    };
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        collections::HashMap,
+        env,
+        fs::{self, File},
+        io::{BufRead, BufReader, Write},
+        path::{Path, PathBuf},
+        process::{self, Command, ExitStatus, Stdio},
+        str::FromStr,
+        sync::atomic::Ordering,
         thread,
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+    /// Interval at which supervised children are checked against their limits.
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    /// Build the `solutions` bin once and return the path of its executable.
+    pub fn build_solutions(is_release: bool) -> Result<PathBuf, Error> {
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bin",
+            "solutions",
+            "--message-format=json-render-diagnostics",
+        ];
 
         if is_release {
             args.push("--release");
         }
 
+        // compiler diagnostics are rendered to stderr, build messages are written to stdout as JSON.
+        let mut cmd = Command::new("cargo")
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+
+        let stdout = BufReader::new(cmd.stdout.take().ok_or(Error::BuildFailed)?);
+
+        let mut executable = None;
+
+        for line in stdout.lines() {
+            if let Some(path) = parse_executable(&line?) {
+                executable = Some(path);
+            }
+        }
+
+        if !cmd.wait()?.success() {
+            return Err(Error::BuildFailed);
+        }
+
+        executable.ok_or(Error::BuildFailed)
+    }
+
+    /// Extract the path of the `solutions` executable from a cargo build message.
+    fn parse_executable(line: &str) -> Option<PathBuf> {
+        let json = JsonValue::from_str(line).ok()?;
+        let message = json.get::<HashMap<String, JsonValue>>()?;
+
+        if message.get("reason")?.get::<String>()? != "compiler-artifact" {
+            return None;
+        }
+
+        let target = message.get("target")?.get::<HashMap<String, JsonValue>>()?;
+        if target.get("name")?.get::<String>()? != "solutions" {
+            return None;
+        }
+
+        message
+            .get("executable")?
+            .get::<String>()
+            .map(PathBuf::from)
+    }
+
+    /// Run the given days in a single invocation of the `solutions` bin and collect the records reported for each part.
+    /// The output is written to `output` if given, and to the inherited stdout otherwise.
+    pub fn run_solutions(
+        bin: &Path,
+        days: &[Day],
+        is_timed: bool,
+        output: Option<&File>,
+    ) -> Result<Vec<PartRecord>, Error> {
+        if days.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut args = year_args();
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            args.extend(timed_args());
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        args.extend(days.iter().map(ToString::to_string));
 
-        let mut cmd = Command::new("cargo")
+        // the child renders human-readable output to stdout/stderr,
+        // and reports structured results through a dedicated file.
+        let results_path = get_results_path(&days[0].to_string());
+        let _ = fs::remove_file(&results_path);
+
+        let (stdout, stderr) = get_stdio(output)?;
+
+        let mut cmd = Command::new(bin)
             .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
+            .env(RESULTS_FILE_ENV, &results_path)
+            .stdout(stdout)
+            .stderr(stderr)
             .spawn()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let status = cmd.wait()?;
+
+        let records = read_records(&results_path);
+        let _ = fs::remove_file(&results_path);
+
+        if !status.success() {
+            return Err(Error::RunFailed);
+        }
+
+        records
+    }
 
-        let mut output = vec![];
+    /// Solve the examples of a manifest for a day. The records are labelled with the index of their example,
+    /// examples that panicked have no record. With `quiet`, the output of the solutions is discarded.
+    pub fn run_examples(
+        bin: &Path,
+        day: Day,
+        manifest: &Path,
+        quiet: bool,
+    ) -> Result<Vec<PartRecord>, Error> {
+        let results_path = get_results_path(&format!("{day}-examples"));
+        let _ = fs::remove_file(&results_path);
+
+        let output = || {
+            if quiet {
+                Stdio::null()
+            } else {
+                Stdio::inherit()
+            }
+        };
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+        Command::new(bin)
+            .args(year_args())
+            .arg("--examples")
+            .arg(manifest)
+            .arg(day.to_string())
+            .env(RESULTS_FILE_ENV, &results_path)
+            .stdout(output())
+            .stderr(output())
+            .spawn()?
+            .wait()?;
+
+        let records = read_records(&results_path);
+        let _ = fs::remove_file(&results_path);
+        records
+    }
+
+    /// Run a day in its own process, so that its input is parsed once for both parts. The process is killed
+    /// if the part it is running exceeds its limits, in which case a `TIMEOUT` or `OOM` record is reported for
+    /// that part and the remaining part is run in a fresh process.
+    pub fn run_day(
+        bin: &Path,
+        day: Day,
+        is_timed: bool,
+        limits: &LimitsConfig,
+        output: Option<&File>,
+    ) -> Result<Vec<PartRecord>, Error> {
+        let inputs = inputs::labels(&inputs::find(day));
+        let mut records: Vec<PartRecord> = vec![];
+        let mut only_part = None;
+
+        loop {
+            let (run_records, killed) =
+                supervise_day(bin, day, only_part, is_timed, limits, &inputs, output)?;
+
+            // NOTE: a rerun repeats the parse stage and the parts that finished before, keep their first record.
+            for record in run_records {
+                if !records
+                    .iter()
+                    .any(|r| r.part == record.part && r.input == record.input)
+                {
+                    records.push(record);
+                }
+            }
+
+            let Some(killed) = killed else {
+                break;
+            };
+
+            match output {
+                Some(mut file) => file.write_all(format_killed(&killed).as_bytes())?,
+                None => print_record(&killed),
+            }
+
+            let other_part = if killed.part == 1 { 2 } else { 1 };
+            records.push(killed);
+
+            let other_part_done = inputs.iter().all(|input| {
+                records
+                    .iter()
+                    .any(|r| r.part == other_part && &r.input == input)
             });
+
+            if only_part.is_some() || other_part_done {
+                break;
+            }
+
+            only_part = Some(other_part);
+        }
+
+        Ok(records)
+    }
+
+    /// Run a day, or only one of its parts, in a process that is killed once the running part exceeds its limits.
+    /// Returns the records reported by the process, and the record of the part it was killed in.
+    fn supervise_day(
+        bin: &Path,
+        day: Day,
+        only_part: Option<u8>,
+        is_timed: bool,
+        limits: &LimitsConfig,
+        inputs: &[Option<String>],
+        output: Option<&File>,
+    ) -> Result<(Vec<PartRecord>, Option<PartRecord>), Error> {
+        let mut args = year_args();
+
+        if is_timed {
+            args.extend(timed_args());
+        }
+
+        if let Some(part) = only_part {
+            args.extend(["--part".into(), part.to_string()]);
+        }
+
+        args.push(day.to_string());
+
+        let parts = only_part.map_or(vec![1, 2], |part| vec![part]);
+
+        // NOTE: benching runs a part many times, its time limit applies to each of these runs.
+        let part_limits = |part| {
+            let limits = limits.for_part(day, part);
+            Limits {
+                time: limits.time.map(|limit| {
+                    if is_timed {
+                        bench_time_limit(limit)
+                    } else {
+                        limit
+                    }
+                }),
+                ..limits
+            }
+        };
+
+        // the process runs every part against every input, polling holds each part to its own limits.
+        let process_limits = Limits {
+            time: parts
+                .iter()
+                .map(|&part| part_limits(part).time)
+                .sum::<Option<Duration>>()
+                .map(|time| time * u32::try_from(inputs.len().max(1)).unwrap_or(u32::MAX)),
+            memory: parts
+                .iter()
+                .try_fold(0, |max, &part| part_limits(part).memory.map(|m| m.max(max))),
+        };
+
+        let results_path = get_results_path(&day.to_string());
+        let _ = fs::remove_file(&results_path);
+
+        let (stdout, stderr) = get_stdio(output)?;
+
+        let mut cmd = Command::new(bin);
+        cmd.args(&args)
+            .env(RESULTS_FILE_ENV, &results_path)
+            .stdout(stdout)
+            .stderr(stderr);
+        set_resource_limits(&mut cmd, process_limits);
+        let mut cmd = cmd.spawn()?;
+
+        let mut running = next_part(None, inputs, &parts);
+        let mut timer = Instant::now();
+        let mut reported = 0;
+
+        // resource limits stop the child right away, polling catches what they do not cover.
+        let killed_status = loop {
+            if let Some(status) = cmd.try_wait()? {
+                break exceeded_limit(status, process_limits);
+            }
+
+            // follow the progress of the child through the records it reported so far.
+            if let Ok(records) = read_records(&results_path)
+                && records.len() != reported
+            {
+                reported = records.len();
+                let next = next_part(records.last(), inputs, &parts);
+                if next != running {
+                    running = next;
+                    timer = Instant::now();
+                }
+            }
+
+            let limits = part_limits(running.1);
+
+            let exceeded = if limits.time.is_some_and(|limit| timer.elapsed() > limit) {
+                Some(PartStatus::Timeout)
+            } else if limits
+                .memory
+                .is_some_and(|limit| read_rss(cmd.id()).is_some_and(|rss| rss > limit))
+            {
+                Some(PartStatus::OutOfMemory)
+            } else {
+                None
+            };
+
+            if exceeded.is_some() {
+                cmd.kill()?;
+                cmd.wait()?;
+                break exceeded;
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        };
+
+        let records = read_records(&results_path);
+        let _ = fs::remove_file(&results_path);
+        let records = records?;
+
+        let killed = killed_status.map(|status| {
+            let (input, part) = next_part(records.last(), inputs, &parts);
+            PartRecord::killed(day, part, status, timer.elapsed()).with_input(input)
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        Ok((records, killed))
+    }
+
+    /// The input and part a child runs after reporting `last`. Parts run in order for every input,
+    /// the first part's time includes parsing the input.
+    fn next_part(
+        last: Option<&PartRecord>,
+        inputs: &[Option<String>],
+        parts: &[u8],
+    ) -> (Option<String>, u8) {
+        let Some(last) = last else {
+            return (inputs.first().cloned().flatten(), parts[0]);
+        };
+
+        if last.is_parse() {
+            return (last.input.clone(), parts[0]);
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        match parts.iter().skip_while(|&&p| p != last.part).nth(1) {
+            Some(&part) => (last.input.clone(), part),
+            None => {
+                let input = inputs
+                    .iter()
+                    .skip_while(|&input| *input != last.input)
+                    .nth(1)
+                    .cloned()
+                    .unwrap_or_else(|| last.input.clone());
+                (input, parts[0])
+            }
+        }
+    }
 
-        Ok(output)
+    /// Bench child invocations, and have them report their samples if requested.
+    fn timed_args() -> Vec<String> {
+        let mut args = vec!["--time".into()];
+        if REPORT_SAMPLES.load(Ordering::Relaxed) {
+            args.push("--samples".into());
+        }
+        args
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+    /// Mirror the selected year to child invocations.
+    pub fn year_args() -> Vec<String> {
+        match Year::selected() {
+            Some(year) => vec!["--year".into(), year.to_string()],
+            None => vec![],
+        }
+    }
+
+    /// Results paths are unique per invocation, as several children may run at the same time.
+    fn get_results_path(tag: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-results-{}-{tag}.jsonl", process::id()))
+    }
+
+    /// Redirect both stdout and stderr of a child to `output`, or inherit them.
+    fn get_stdio(output: Option<&File>) -> Result<(Stdio, Stdio), Error> {
+        match output {
+            Some(file) => Ok((file.try_clone()?.into(), file.try_clone()?.into())),
+            None => Ok((Stdio::inherit(), Stdio::inherit())),
+        }
+    }
+
+    fn read_records(path: &Path) -> Result<Vec<PartRecord>, Error> {
+        match fs::read_to_string(path) {
+            Ok(content) => parse_records(&content).map_err(Error::Parser),
+            // the child did not report anything, e.g. because it was killed before finishing a part.
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(vec![]),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Have the kernel enforce the limits of a child: its memory as a limit of its address space,
+    /// and its time as a limit of its CPU time.
+    #[cfg(target_os = "linux")]
+    fn set_resource_limits(cmd: &mut Command, limits: Limits) {
+        use std::os::unix::process::CommandExt;
+
+        if limits.is_empty() {
+            return;
+        }
+
+        let set_limit = |resource, value: u64| {
+            let limit = libc::rlimit {
+                rlim_cur: value,
+                rlim_max: value,
+            };
+            // SAFETY: `setrlimit` only reads the passed limit.
+            match unsafe { libc::setrlimit(resource, &limit) } {
+                0 => Ok(()),
+                _ => Err(std::io::Error::last_os_error()),
+            }
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+        // SAFETY: the closure only calls `setrlimit`, which is async-signal-safe, and does not allocate.
+        unsafe {
+            cmd.pre_exec(move || {
+                if let Some(bytes) = limits.memory {
+                    set_limit(libc::RLIMIT_AS, bytes)?;
                 }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                if let Some(time) = limits.time {
+                    // NOTE: CPU time is limited in whole seconds, the wall-clock limit is still polled.
+                    set_limit(libc::RLIMIT_CPU, time.as_secs() + 1)?;
                 }
-
-                timings.total_nanos += nanos;
+                Ok(())
             });
+        }
+    }
+
+    /// Resource limits are only set on Linux, elsewhere limits are enforced by polling alone.
+    #[cfg(not(target_os = "linux"))]
+    fn set_resource_limits(_cmd: &mut Command, _limits: Limits) {}
 
-        timings
+    /// The limit a child exceeded, judging by the signal that stopped it.
+    /// A child that runs out of address space aborts when an allocation fails.
+    #[cfg(target_os = "linux")]
+    fn exceeded_limit(status: ExitStatus, limits: Limits) -> Option<PartStatus> {
+        use std::os::unix::process::ExitStatusExt;
+
+        match status.signal()? {
+            libc::SIGXCPU if limits.time.is_some() => Some(PartStatus::Timeout),
+            libc::SIGABRT if limits.memory.is_some() => Some(PartStatus::OutOfMemory),
+            _ => None,
+        }
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    #[cfg(not(target_os = "linux"))]
+    fn exceeded_limit(_status: ExitStatus, _limits: Limits) -> Option<PartStatus> {
+        None
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    /// Current resident set size of a process in bytes.
+    #[cfg(target_os = "linux")]
+    fn read_rss(pid: u32) -> Option<u64> {
+        let status = fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+        let kilobytes: u64 = status
+            .lines()
+            .find_map(|line| line.strip_prefix("VmRSS:"))?
+            .trim()
+            .strip_suffix("kB")?
+            .trim()
+            .parse()
+            .ok()?;
+        Some(kilobytes * 1024)
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    /// Memory limits are only enforced on Linux.
+    #[cfg(not(target_os = "linux"))]
+    fn read_rss(_pid: u32) -> Option<u64> {
+        None
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
+        use super::{next_part, parse_executable};
+        use crate::{
+            day,
+            template::{record::PartRecord, stats::BenchStats},
+        };
+        use std::{path::PathBuf, time::Duration};
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn parses_solutions_executable() {
+            let line = r#"{"reason":"compiler-artifact","target":{"name":"solutions","kind":["bin"]},"executable":"/tmp/target/release/solutions","fresh":true}"#;
+            assert_eq!(
+                parse_executable(line),
+                Some(PathBuf::from("/tmp/target/release/solutions"))
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn ignores_other_messages() {
+            let lib = r#"{"reason":"compiler-artifact","target":{"name":"advent_of_code","kind":["lib"]},"executable":null}"#;
+            assert_eq!(parse_executable(lib), None);
+            assert_eq!(
+                parse_executable(r#"{"reason":"build-finished","success":true}"#),
+                None
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(parse_executable("not json"), None);
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn follows_parts_and_inputs() {
+            let inputs = [Some("alice".to_string()), Some("bob".to_string())];
+            let record = |part, input: &str| {
+                let stats = BenchStats::single(Duration::from_millis(1));
+                PartRecord::new(day!(1), part, None, stats).with_input(Some(input.into()))
+            };
+            let parse = PartRecord::parse(day!(1), BenchStats::single(Duration::from_millis(1)))
+                .with_input(Some("bob".into()));
+            let alice = Some("alice".to_string());
+            let bob = Some("bob".to_string());
+
+            assert_eq!(next_part(None, &inputs, &[1, 2]), (alice.clone(), 1));
+            assert_eq!(
+                next_part(Some(&record(1, "alice")), &inputs, &[1, 2]),
+                (alice, 2)
+            );
+            assert_eq!(
+                next_part(Some(&record(2, "alice")), &inputs, &[1, 2]),
+                (bob.clone(), 1)
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(next_part(Some(&parse), &inputs, &[2]), (bob.clone(), 2));
+            assert_eq!(next_part(Some(&record(2, "bob")), &inputs, &[2]), (bob, 2));
         }
     }
 }
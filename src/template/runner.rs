@@ -1,63 +1,261 @@
 /// Encapsulates code that interacts with solution functions.
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{Write, stdout};
-use std::process::Output;
+use std::io::{self, IsTerminal, Write, stdout};
+use std::panic::{self, RefUnwindSafe};
+use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, process, thread};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::SubmitOutcome;
+use crate::template::alloc::{self, AllocStats};
+use crate::template::answers::{Answers, Check};
+use crate::template::inputs::{self, DEFAULT_INPUT_NAME};
+use crate::template::options::{self, InputSource, RunOptions};
+use crate::template::record::{PartRecord, PartStatus};
+use crate::template::stats::BenchStats;
+use crate::template::submissions::{self, SubmissionLog};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, Solution, backend};
+
+/// Execution time a part is benched for, unless that takes fewer than 10 samples.
+const BENCH_TIME: Duration = Duration::from_secs(1);
+
+/// One in this many bench iterations is additionally run as warmup.
+const WARMUP_DIVISOR: u128 = 10;
+
+/// Submissions that were rate-limited are retried at most this many times with `--wait`.
+const MAX_SUBMIT_ATTEMPTS: usize = 3;
+
+/// Label of the input that is currently being solved, see [`run_inputs`].
+static CURRENT_INPUT: Mutex<Option<String>> = Mutex::new(None);
+
+/// Entry point of solution binaries. Runs `solution` against the input passed on the command-line,
+/// or against every input of the day in `data/inputs` if none was passed. See [`RunOptions`] for all options.
+pub fn run_main(day: Day, solution: impl Fn(&str) + RefUnwindSafe) {
+    let input = options::current().input.clone().or_else(positional_input);
+
+    let solved = match input {
+        Some(source) => run_source(&source, solution),
+        None => run_inputs(day, solution),
+    };
+
+    if !solved {
+        process::exit(1);
+    }
+}
+
+/// Run `solution` against an input passed on the command-line.
+/// Returns `false` if the input could not be read or the solution panicked.
+pub fn run_source(source: &InputSource, solution: impl Fn(&str) + RefUnwindSafe) -> bool {
+    match source.read() {
+        // NOTE: labelling the input keeps answers of custom inputs from being checked or submitted.
+        Ok(content) => run_input(Some(source.label()), &content, &solution),
+        Err(e) => {
+            eprintln!("{e}");
+            false
+        }
+    }
+}
+
+/// An input path passed as a free argument, e.g. `cargo run --bin 01 -- input.txt`.
+fn positional_input() -> Option<InputSource> {
+    let mut args = pico_args::Arguments::from_env();
+    let _ = RunOptions::from_args(&mut args);
+    let free = args.finish();
+
+    match free.as_slice() {
+        [] => None,
+        [input] => Some(InputSource::parse(&input.to_string_lossy())),
+        _ => {
+            eprintln!(
+                "Unexpected arguments: {free:?}. Expected at most one input path or `-` for stdin."
+            );
+            process::exit(1);
+        }
+    }
+}
+
+/// Run `solution` against every input of a day. With multiple inputs, results are printed and reported per input.
+/// Returns `false` if the inputs could not be read or the solution panicked for one of them.
+pub fn run_inputs(day: Day, solution: impl Fn(&str) + RefUnwindSafe) -> bool {
+    let inputs = match inputs::read_all(day) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("{e}");
+            return false;
+        }
+    };
+
+    let mut success = true;
+
+    for input in inputs {
+        if let Some(label) = &input.label
+            && !options::current().quiet
+        {
+            println!("{ANSI_ITALIC}Input: {label}{ANSI_RESET}");
+        }
+
+        // NOTE: a panicking solution should not prevent the remaining inputs from being solved.
+        success &= run_input(input.label, &input.content, &solution);
+    }
+
+    success
+}
+
+/// Run `solution` against a single input. Returns `false` if the solution panicked.
+fn run_input(
+    label: Option<String>,
+    input: &str,
+    solution: &(impl Fn(&str) + RefUnwindSafe),
+) -> bool {
+    set_current_input(label);
+    // The panic message is printed to stderr by the default hook.
+    let success = panic::catch_unwind(|| solution(input)).is_ok();
+    set_current_input(None);
+    success
+}
+
+fn set_current_input(label: Option<String>) {
+    *CURRENT_INPUT.lock().unwrap_or_else(|e| e.into_inner()) = label;
+}
+
+fn current_input() -> Option<String> {
+    CURRENT_INPUT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
 
 pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+    if options::current()
+        .part
+        .is_some_and(|selected| selected != part)
+    {
+        return;
+    }
+
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, stats, alloc, samples) = run_timed(func, input, |result| {
+        if !options::current().quiet {
+            let answer = result.as_ref().map(ToString::to_string);
+            print_result(answer.as_deref(), &part_str, "");
+        }
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let record = PartRecord::new(day, part, result.as_ref().map(ToString::to_string), stats)
+        .with_input(current_input())
+        .with_alloc(alloc)
+        .with_samples(samples);
+    print_record(&record);
+    record.emit();
 
     if let Some(result) = result {
         submit_result(result, day, part);
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a [`Solution`]: the input is parsed once, then both parts are run against the parsed value.
+/// Parsing is timed and reported separately from the parts.
+pub fn run_solution<S: Solution>(input: &str, day: Day) {
+    let parsed = run_parse(S::parse, input, day);
+    run_part(S::part_one, &parsed, day, 1);
+    run_part(S::part_two, &parsed, day, 2);
+}
+
+fn run_parse<'a, P>(func: impl Fn(&'a str) -> P, input: &'a str, day: Day) -> P {
+    let (parsed, stats, alloc, samples) = run_timed(func, input, |_| {
+        if !options::current().quiet {
+            print!("Parse:");
+        }
+    });
+
+    let record = PartRecord::parse(day, stats)
+        .with_input(current_input())
+        .with_alloc(alloc)
+        .with_samples(samples);
+    print_record(&record);
+    record.emit();
+
+    parsed
+}
+
+/// Run a solution part. The behavior differs depending on the options the binary was started with:
+///  1. by default, the function is executed once.
+///  2. with `--time`, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  3. with `--repeat N`, the function is executed N times.
+///
+/// Allocations are counted during the first execution only.
+/// The raw samples of a benchmark are only returned with `--samples`.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, BenchStats, Option<AllocStats>, Vec<Duration>) {
     let timer = Instant::now();
-    let result = {
+    let (result, alloc) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        alloc::measure(|| func(input))
     };
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let options = options::current();
+
+    let (stats, samples) = if options.time {
         bench(func, input, &base_time)
+    } else if let Some(repeat) = options.repeat.filter(|repeat| *repeat > 1) {
+        (repeat_runs(func, input, base_time, repeat), vec![])
     } else {
-        (base_time, 1)
+        (BenchStats::single(base_time), vec![])
     };
 
-    (result, run.0, run.1)
+    let samples = if options.samples { samples } else { vec![] };
+
+    (result, stats, alloc, samples)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Execute a solution part `repeat` times in total, including the run that took `first`.
+fn repeat_runs<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    first: Duration,
+    repeat: u32,
+) -> BenchStats {
+    let mut timers = vec![first];
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    for _ in 1..repeat {
+        let timer = Instant::now();
+        black_box(func(black_box(input)));
+        timers.push(timer.elapsed());
+    }
+
+    // NOTE: there is always at least one sample.
+    BenchStats::from_samples(&timers).unwrap()
+}
+
+/// Bench a solution part. A share of the iterations is run as warmup and discarded before samples are collected.
+/// Returns the statistics along with the raw samples.
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+) -> (BenchStats, Vec<Duration>) {
+    if !options::current().quiet {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+
+    let bench_iterations = bench_iterations(base_time);
+    let warmup_iterations = warmup_iterations(bench_iterations);
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input)));
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,35 +265,127 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    // NOTE: `bench_iterations` is at least 10, so there is always at least one sample.
+    (BenchStats::from_samples(&timers).unwrap(), timers)
+}
+
+/// Approx. 1 second of execution time or 10 samples, whatever takes longer.
+fn bench_iterations(base_time: &Duration) -> u128 {
+    (BENCH_TIME.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn warmup_iterations(bench_iterations: u128) -> u128 {
+    cmp::max(bench_iterations / WARMUP_DIVISOR, 1)
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+/// The wall-clock time that benching a part may take if none of its runs exceeds `limit`.
+/// This includes the first run, the warmup, and the parse stage, which is benched separately.
+pub fn bench_time_limit(limit: Duration) -> Duration {
+    let runs = bench_iterations(&limit);
+    let at_limit = limit.saturating_mul((1 + runs + warmup_iterations(runs)) as u32);
+
+    // NOTE: faster runs are benched more often, but their samples add up to about `BENCH_TIME`.
+    let below_limit = limit + BENCH_TIME + BENCH_TIME / WARMUP_DIVISOR as u32;
+
+    at_limit.max(below_limit).saturating_mul(2)
+}
+
+fn format_alloc(alloc: Option<&AllocStats>) -> String {
+    alloc.map_or_else(String::new, |alloc| format!(" [{}]", alloc.summary()))
+}
+
+fn format_stats(stats: &BenchStats) -> String {
+    if stats.samples == 1 {
+        format!(" ({:.1?})", stats.median)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({:.1?} ± {:.1?} @ {} samples, min {:.1?}, max {:.1?}, p95 {:.1?}, p99 {:.1?}, {} outliers)",
+            stats.median,
+            stats.std_dev,
+            stats.samples,
+            stats.min,
+            stats.max,
+            stats.p95,
+            stats.p99,
+            stats.outliers
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+/// Render the final output line(s) of a part.
+pub(crate) fn print_record(record: &PartRecord) {
+    // NOTE: in quiet mode, only answers are printed. Unsolved parts print an empty line, so that answers stay on their line.
+    if options::current().quiet {
+        if !record.is_parse() {
+            println!("{}", record.answer.as_deref().unwrap_or_default());
+        }
+        return;
+    }
+
+    if record.is_parse() {
+        print!("\r");
+        println!(
+            "Parse:{}{}",
+            format_stats(&record.stats),
+            format_alloc(record.alloc.as_ref())
+        );
+        return;
+    }
+
+    match record.status {
+        PartStatus::Timeout | PartStatus::OutOfMemory => {
+            print!("{}", format_killed(record));
+            return;
+        }
+        PartStatus::Solved | PartStatus::Unsolved => {}
+    }
+
+    print_result(
+        record.answer.as_deref(),
+        &format!("Part {}", record.part),
+        &format!(
+            "{}{}{}",
+            format_check(record),
+            format_stats(&record.stats),
+            format_alloc(record.alloc.as_ref())
+        ),
+    );
+}
+
+/// Mark an answer as correct or wrong if the part has a known answer in `data/answers.json`.
+fn format_check(record: &PartRecord) -> String {
+    static ANSWERS: OnceLock<Answers> = OnceLock::new();
+
+    let answers = ANSWERS.get_or_init(|| {
+        Answers::read_from_file().unwrap_or_else(|e| {
+            eprintln!("Failed to read known answers: {e}");
+            Answers::default()
+        })
+    });
+
+    match answers.check(record) {
+        Some(Check::Correct) => " ✔".into(),
+        Some(Check::Wrong(expected)) => format!(" ✘ (expected {expected})"),
+        None => String::new(),
+    }
+}
+
+/// Render the line of a part that was killed by its supervisor.
+pub(crate) fn format_killed(record: &PartRecord) -> String {
+    format!(
+        "\rPart {}: {ANSI_BOLD}{}{ANSI_RESET} (killed after {:.1?})\n",
+        record.part,
+        record.status.to_string().to_uppercase(),
+        record.stats.median
+    )
+}
+
+fn print_result(result: Option<&str>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
-            if result.to_string().contains('\n') {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -125,43 +415,149 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Try to submit one part of the solution if:
+///  1. `--submit` was passed for this part.
+///  2. the answer passes the guard of the local submission log.
+///  3. the backend used for submitting is set up.
+///
+/// With `--wait`, cooldowns from previous answers are waited out instead of skipping the submission.
+fn submit_result<T: Display>(result: T, day: Day, part: u8) {
+    if options::current().submit != Some(part) {
+        return;
+    }
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+    // only the default input belongs to the account that answers are submitted for.
+    if let Some(input) = current_input().filter(|input| input != DEFAULT_INPUT_NAME) {
+        println!("Not submitting the answer of input `{input}`.");
+        return;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+    let answer = result.to_string();
+
+    let mut log = SubmissionLog::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read submission log: {e}");
         process::exit(1);
+    });
+
+    match log.guard(day, part, &answer) {
+        Err(reason) => {
+            println!("Not submitting: {reason}");
+            return;
+        }
+        Ok(warnings) => {
+            for warning in &warnings {
+                println!("Warning: {warning}");
+            }
+
+            if !warnings.is_empty() && !confirm("Submit anyway?") {
+                return;
+            }
+        }
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    let backend = backend::from_env();
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+    if let Err(e) = backend.check() {
+        eprintln!("{e}");
         process::exit(1);
+    }
+
+    let wait = options::current().wait;
+
+    for _ in 0..MAX_SUBMIT_ATTEMPTS {
+        if let Some(cooldown) = log.cooldown() {
+            if !wait {
+                println!(
+                    "Not submitting: the cooldown of a previous answer ends in {}. Pass `--wait` to wait it out.",
+                    submissions::format_wait(cooldown)
+                );
+                return;
+            }
+
+            wait_for(cooldown);
+        }
+
+        println!("Submitting result...");
+
+        let outcome = match backend.submit(day, part, &answer) {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                eprintln!("Failed to submit: {e}");
+                return;
+            }
+        };
+
+        println!("{outcome}");
+        record_outcome(&mut log, day, part, &answer, &outcome);
+
+        if let SubmitOutcome::RateLimited { wait: cooldown } = outcome {
+            if !wait {
+                println!("Pass `--wait` to wait out the cooldown and resubmit.");
+                return;
+            }
+
+            // NOTE: a known cooldown was recorded and is waited out before the next attempt.
+            // Otherwise, wait the shortest cooldown imposed by adventofcode.com.
+            if cooldown.is_none() {
+                wait_for(Duration::from_secs(60));
+            }
+            continue;
+        }
+
+        return;
+    }
+
+    println!("Giving up after {MAX_SUBMIT_ATTEMPTS} attempts.");
+}
+
+/// Store the outcome of a submission in the submission log. Correct answers are also stored as known answers.
+fn record_outcome(
+    log: &mut SubmissionLog,
+    day: Day,
+    part: u8,
+    answer: &str,
+    outcome: &SubmitOutcome,
+) {
+    let Some((verdict, hint, cooldown)) = outcome.to_log() else {
+        return;
     };
 
-    if part_submit != part {
-        return None;
+    log.record(day, part, answer, verdict, hint, cooldown);
+    if let Err(e) = log.store_file() {
+        eprintln!("Failed to store submission log: {e}");
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+    if *outcome == SubmitOutcome::Correct {
+        let stored = Answers::read_from_file().and_then(|mut answers| {
+            answers.set(day, part, None, answer);
+            answers.store_file().map_err(|e| e.to_string())
+        });
+
+        if let Err(e) = stored {
+            eprintln!("Failed to store known answer: {e}");
+        }
+    }
+}
+
+fn wait_for(cooldown: Duration) {
+    println!(
+        "Waiting {} for the cooldown to pass...",
+        submissions::format_wait(cooldown)
+    );
+    // NOTE: wait a little longer, so that the cooldown has passed on the server as well.
+    thread::sleep(cooldown + Duration::from_secs(1));
+}
+
+/// Ask a yes/no question on the terminal. Without a terminal, the answer is no.
+pub(crate) fn confirm(question: &str) -> bool {
+    if !io::stdin().is_terminal() {
+        println!("{question} Not confirmed, stdin is not a terminal.");
+        return false;
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    print!("{question} [y/N] ");
+    let _ = stdout().flush();
+
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).is_ok() && line.trim().eq_ignore_ascii_case("y")
 }
@@ -0,0 +1,270 @@
+//! Complexity scaling benchmarks.
+//!
+//! A part is run against generated inputs of growing size. The exponent `k` of `time ~ size^k` is estimated
+//! with a linear regression of `ln(time)` on `ln(size)`, which gives a rough idea of the part's complexity class.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::template::registry::GeneratorFn;
+use crate::template::stats::BenchStats;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+
+/// Every size is sampled at least this many times, unless a single run exceeds the time limit.
+const MIN_SAMPLES: usize = 5;
+const MAX_SAMPLES: usize = 100;
+/// Time spent sampling a single size, once the minimum number of samples is reached.
+const SAMPLE_BUDGET: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScaleOptions {
+    /// Size of the smallest generated input.
+    pub start: usize,
+    /// Number of sizes to measure. Each size is `factor` times the previous one.
+    pub steps: usize,
+    pub factor: usize,
+    /// Larger sizes are skipped once a single run takes longer than this.
+    pub max_time: Duration,
+}
+
+impl Default for ScaleOptions {
+    fn default() -> Self {
+        Self {
+            start: 100,
+            steps: 8,
+            factor: 2,
+            max_time: Duration::from_secs(2),
+        }
+    }
+}
+
+impl ScaleOptions {
+    /// Parse `--start <size>`, `--steps <n>`, `--factor <n>` and `--max-time <seconds>`, falling back to the defaults.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let defaults = Self::default();
+        let max_time: Option<f64> = args.opt_value_from_str("--max-time")?;
+
+        Ok(Self {
+            start: args
+                .opt_value_from_str("--start")?
+                .unwrap_or(defaults.start),
+            steps: args
+                .opt_value_from_str("--steps")?
+                .unwrap_or(defaults.steps),
+            factor: args
+                .opt_value_from_str("--factor")?
+                .unwrap_or(defaults.factor),
+            max_time: max_time.map_or(defaults.max_time, Duration::from_secs_f64),
+        })
+    }
+
+    /// Forward the options to a child process.
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--start".into(),
+            self.start.to_string(),
+            "--steps".into(),
+            self.steps.to_string(),
+            "--factor".into(),
+            self.factor.to_string(),
+            "--max-time".into(),
+            self.max_time.as_secs_f64().to_string(),
+        ]
+    }
+
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.steps).scan(self.start.max(1), |size, _| {
+            let current = *size;
+            *size = size.saturating_mul(self.factor.max(2));
+            Some(current)
+        })
+    }
+}
+
+/// The median time of a part for one input size.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScalePoint {
+    pub size: usize,
+    pub time: Duration,
+}
+
+/// Result of fitting `time ~ size^exponent` to a series of measurements.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fit {
+    pub exponent: f64,
+    /// Coefficient of determination of the regression, `1.0` is a perfect fit.
+    pub r_squared: f64,
+}
+
+/// Measure `solve` against inputs of growing size.
+pub fn measure<T>(
+    solve: impl Fn(&str) -> T,
+    generator: GeneratorFn,
+    options: &ScaleOptions,
+) -> Vec<ScalePoint> {
+    let mut points = vec![];
+
+    for size in options.sizes() {
+        let input = generator(size);
+        let timer = Instant::now();
+        let mut samples = vec![];
+
+        loop {
+            let sample_timer = Instant::now();
+            black_box(solve(black_box(&input)));
+            samples.push(sample_timer.elapsed());
+
+            let elapsed = timer.elapsed();
+            if samples.len() >= MAX_SAMPLES
+                || (samples.len() >= MIN_SAMPLES && elapsed >= SAMPLE_BUDGET)
+                || elapsed >= options.max_time
+            {
+                break;
+            }
+        }
+
+        // NOTE: there is always at least one sample.
+        let time = BenchStats::from_samples(&samples).unwrap().median;
+        points.push(ScalePoint { size, time });
+
+        if time >= options.max_time {
+            break;
+        }
+    }
+
+    points
+}
+
+/// Least-squares fit of `ln(time) = exponent * ln(size) + c`. Needs at least two distinct sizes.
+pub fn fit(points: &[ScalePoint]) -> Option<Fit> {
+    let coords: Vec<(f64, f64)> = points
+        .iter()
+        .filter(|p| p.size > 0 && !p.time.is_zero())
+        .map(|p| ((p.size as f64).ln(), p.time.as_secs_f64().ln()))
+        .collect();
+
+    if coords.len() < 2 {
+        return None;
+    }
+
+    let n = coords.len() as f64;
+    let mean_x = coords.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = coords.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let ss_xx: f64 = coords.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    let ss_xy: f64 = coords
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let ss_yy: f64 = coords.iter().map(|(_, y)| (y - mean_y).powi(2)).sum();
+
+    if ss_xx == 0.0 {
+        return None;
+    }
+
+    let exponent = ss_xy / ss_xx;
+    let r_squared = if ss_yy == 0.0 {
+        1.0
+    } else {
+        ss_xy.powi(2) / (ss_xx * ss_yy)
+    };
+
+    Some(Fit {
+        exponent,
+        r_squared,
+    })
+}
+
+/// The complexity class closest to an empirical exponent.
+pub fn complexity_class(exponent: f64) -> &'static str {
+    match exponent {
+        e if e < 0.25 => "O(1)",
+        e if e < 0.75 => "O(√n)",
+        e if e < 1.15 => "O(n)",
+        e if e < 1.5 => "O(n log n)",
+        e if e < 2.5 => "O(n²)",
+        e if e < 3.5 => "O(n³)",
+        _ => "O(n⁴) or worse",
+    }
+}
+
+pub fn print_report(day: Day, part: u8, points: &[ScalePoint]) {
+    println!("{ANSI_BOLD}Day {day}, Part {part}{ANSI_RESET}");
+    println!("{:>10}  {:>12}  {:>8}", "Size", "Time", "Growth");
+
+    for (i, point) in points.iter().enumerate() {
+        let growth = i
+            .checked_sub(1)
+            .and_then(|prev| points.get(prev))
+            .filter(|prev| !prev.time.is_zero())
+            .map_or_else(
+                || "-".into(),
+                |prev| format!("{:.2}×", point.time.as_secs_f64() / prev.time.as_secs_f64()),
+            );
+
+        println!(
+            "{:>10}  {:>12}  {growth:>8}",
+            point.size,
+            format!("{:.1?}", point.time)
+        );
+    }
+
+    match fit(points) {
+        Some(fit) => println!(
+            "Exponent: {ANSI_ITALIC}{:.2}{ANSI_RESET} (R² {:.3}), estimated {ANSI_BOLD}{}{ANSI_RESET}",
+            fit.exponent,
+            fit.r_squared,
+            complexity_class(fit.exponent)
+        ),
+        None => println!("Not enough measurements to estimate the complexity."),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{ScaleOptions, ScalePoint, complexity_class, fit};
+
+    fn points(f: impl Fn(f64) -> f64) -> Vec<ScalePoint> {
+        [100, 200, 400, 800, 1600]
+            .into_iter()
+            .map(|size| ScalePoint {
+                size,
+                time: Duration::from_secs_f64(f(size as f64)),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fits_exponents() {
+        let linear = fit(&points(|n| n * 1e-6)).unwrap();
+        assert!((linear.exponent - 1.0).abs() < 1e-6);
+        assert!((linear.r_squared - 1.0).abs() < 1e-6);
+
+        let quadratic = fit(&points(|n| n * n * 1e-9)).unwrap();
+        assert!((quadratic.exponent - 2.0).abs() < 1e-6);
+        assert_eq!(complexity_class(quadratic.exponent), "O(n²)");
+
+        let n_log_n = fit(&points(|n| n * n.ln() * 1e-7)).unwrap();
+        assert_eq!(complexity_class(n_log_n.exponent), "O(n log n)");
+    }
+
+    #[test]
+    fn needs_two_sizes() {
+        assert_eq!(fit(&points(|n| n)[..1]), None);
+    }
+
+    #[test]
+    fn generates_sizes() {
+        let options = ScaleOptions {
+            start: 10,
+            steps: 4,
+            factor: 3,
+            ..ScaleOptions::default()
+        };
+        assert_eq!(options.sizes().collect::<Vec<_>>(), vec![10, 30, 90, 270]);
+    }
+}
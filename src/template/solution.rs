@@ -0,0 +1,51 @@
+use std::fmt::Display;
+
+/// A solution that shares a parsed representation of the input between both parts.
+///
+/// Use it with `solution!(DAY, Type)`. The runner then times `parse`, `part_one` and `part_two` separately.
+///
+/// ```ignore
+/// advent_of_code::solution!(1, Dial);
+///
+/// pub struct Dial;
+///
+/// impl Solution for Dial {
+///     type Parsed<'a> = Vec<&'a str>;
+///     type Answer1 = usize;
+///     type Answer2 = usize;
+///
+///     fn parse(input: &str) -> Self::Parsed<'_> {
+///         input.lines().collect()
+///     }
+///
+///     fn part_one(lines: &Self::Parsed<'_>) -> Option<Self::Answer1> {
+///         Some(lines.len())
+///     }
+///
+///     fn part_two(_: &Self::Parsed<'_>) -> Option<Self::Answer2> {
+///         None
+///     }
+/// }
+/// ```
+pub trait Solution {
+    /// The parsed input, which may borrow from the raw input.
+    type Parsed<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Option<Self::Answer1>;
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Option<Self::Answer2>;
+
+    /// Parses the input and solves part one, e.g. for use in tests.
+    fn solve_part_one(input: &str) -> Option<Self::Answer1> {
+        Self::part_one(&Self::parse(input))
+    }
+
+    /// Parses the input and solves part two, e.g. for use in tests.
+    fn solve_part_two(input: &str) -> Option<Self::Answer2> {
+        Self::part_two(&Self::parse(input))
+    }
+}
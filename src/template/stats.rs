@@ -0,0 +1,164 @@
+//! Summary statistics over the samples collected by a benchmark run.
+
+use std::time::Duration;
+
+/// Samples whose modified z-score exceeds this value are rejected as outliers.
+/// See: Iglewicz and Hoaglin, "How to Detect and Handle Outliers" (1993).
+const OUTLIER_THRESHOLD: f64 = 3.5;
+
+/// Scales the median absolute deviation to be consistent with the standard deviation of a normal distribution.
+const MAD_SCALE: f64 = 0.6745;
+
+/// Statistical summary of a set of benchmark samples, computed after outlier rejection.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchStats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    /// Number of samples collected, including rejected outliers.
+    pub samples: u128,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Summarizes a set of samples. Returns [`None`] if `samples` is empty.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let kept = reject_outliers(&nanos);
+
+        let count = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / count;
+        let variance = kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count;
+
+        Some(Self {
+            median: to_duration(median(&kept)),
+            min: to_duration(kept[0]),
+            max: to_duration(kept[kept.len() - 1]),
+            std_dev: to_duration(variance.sqrt()),
+            p95: to_duration(percentile(&kept, 95.0)),
+            p99: to_duration(percentile(&kept, 99.0)),
+            samples: nanos.len() as u128,
+            outliers: (nanos.len() - kept.len()) as u128,
+        })
+    }
+
+    /// Wraps the duration of a single, unbenched run.
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            median: duration,
+            min: duration,
+            max: duration,
+            std_dev: Duration::ZERO,
+            p95: duration,
+            p99: duration,
+            samples: 1,
+            outliers: 0,
+        }
+    }
+}
+
+/// Drops samples whose modified z-score (based on the median absolute deviation) exceeds [`OUTLIER_THRESHOLD`].
+/// Expects `sorted` to be sorted in ascending order, the result is sorted as well.
+fn reject_outliers(sorted: &[f64]) -> Vec<f64> {
+    let center = median(sorted);
+
+    let mut deviations: Vec<f64> = sorted.iter().map(|x| (x - center).abs()).collect();
+    deviations.sort_unstable_by(f64::total_cmp);
+    let mad = median(&deviations);
+
+    // NOTE: with a MAD of zero, more than half of the samples are identical and every other sample
+    // would be flagged. Keep all samples in that case instead.
+    if mad == 0.0 {
+        return sorted.to_vec();
+    }
+
+    sorted
+        .iter()
+        .copied()
+        .filter(|x| MAD_SCALE * (x - center).abs() / mad <= OUTLIER_THRESHOLD)
+        .collect()
+}
+
+fn median(sorted: &[f64]) -> f64 {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Nearest-rank percentile of a sorted, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::BenchStats;
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let stats = BenchStats::from_samples(&nanos(&[40, 10, 30, 20])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(25));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(40));
+        assert_eq!(stats.std_dev, Duration::from_nanos(11));
+        assert_eq!(stats.p95, Duration::from_nanos(40));
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[100, 101, 99, 102, 98, 100, 5000])).unwrap();
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(102));
+        assert_eq!(stats.median, Duration::from_nanos(100));
+    }
+
+    #[test]
+    fn keeps_samples_with_zero_deviation() {
+        let stats = BenchStats::from_samples(&nanos(&[100, 100, 100, 500])).unwrap();
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.max, Duration::from_nanos(500));
+    }
+
+    #[test]
+    fn computes_percentiles() {
+        let samples: Vec<u64> = (1..=100).collect();
+        let stats = BenchStats::from_samples(&nanos(&samples)).unwrap();
+        assert_eq!(stats.p95, Duration::from_nanos(95));
+        assert_eq!(stats.p99, Duration::from_nanos(99));
+    }
+}
@@ -0,0 +1,309 @@
+//! The progress of an event per day, as shown by `cargo status`: which puzzles are unlocked, which days
+//! have a solution, inputs and examples, whether the examples pass, and the answers, submissions and
+//! timings that were recorded for them.
+
+use std::fs;
+
+use crate::template::answers::Answers;
+use crate::template::history::format_nanos;
+use crate::template::submissions::{Outcome, SubmissionLog};
+use crate::template::timings::Timings;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, year_path};
+
+/// Longest answer shown in the table, longer ones are shortened.
+const MAX_ANSWER_WIDTH: usize = 14;
+
+/// Presence of a data file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileState {
+    Missing,
+    Empty,
+    Present,
+}
+
+impl FileState {
+    pub fn of(path: &str) -> Self {
+        match fs::read_to_string(path) {
+            Err(_) => FileState::Missing,
+            Ok(content) if content.trim().is_empty() => FileState::Empty,
+            Ok(_) => FileState::Present,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            FileState::Missing => "-",
+            FileState::Empty => "empty",
+            FileState::Present => "✔",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartStatus {
+    /// The known answer from `data/answers.json`, or the answer that was accepted on submission.
+    pub answer: Option<String>,
+    /// Whether the part was solved, i.e. earned a star.
+    pub solved: bool,
+    /// The number of wrong answers that were submitted.
+    pub wrong: usize,
+}
+
+impl PartStatus {
+    pub fn from_records(answers: &Answers, log: &SubmissionLog, day: Day, part: u8) -> Self {
+        let submissions = log.get(day, part);
+
+        let accepted = submissions
+            .iter()
+            .rev()
+            .find(|s| s.outcome == Outcome::Correct)
+            .map(|s| s.answer.clone());
+
+        let solved_before = submissions
+            .iter()
+            .any(|s| s.outcome == Outcome::AlreadySolved);
+
+        let answer = answers.get(day, part, None).map(String::from).or(accepted);
+
+        PartStatus {
+            solved: answer.is_some() || solved_before,
+            answer,
+            wrong: submissions
+                .iter()
+                .filter(|s| s.outcome == Outcome::Wrong)
+                .count(),
+        }
+    }
+
+    fn describe(&self) -> String {
+        let answer = self.answer.as_deref().map(shorten);
+
+        match (self.solved, answer, self.wrong) {
+            (true, Some(answer), _) => format!("★ {answer}"),
+            (true, None, _) => "★".into(),
+            (false, _, 0) => "-".into(),
+            (false, _, 1) => "✘ 1 wrong".into(),
+            (false, _, wrong) => format!("✘ {wrong} wrong"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: Day,
+    pub unlocked: bool,
+    /// Whether `src/bin/NN.rs` exists.
+    pub solution: bool,
+    pub input: FileState,
+    pub example: FileState,
+    /// The number of matching examples and the number of examples, if they were checked.
+    pub examples: Option<(usize, usize)>,
+    pub parts: [PartStatus; 2],
+    /// The total of the stored timings, if the day has any.
+    pub total_nanos: Option<u64>,
+    /// Whether both parts have stored timings.
+    pub timed: bool,
+}
+
+impl DayStatus {
+    /// Read the status of a day from its files. The examples are not checked.
+    pub fn read(
+        day: Day,
+        unlocked: bool,
+        answers: &Answers,
+        log: &SubmissionLog,
+        timings: &Timings,
+    ) -> Self {
+        let timing = timings.data.iter().find(|t| t.day == day);
+
+        DayStatus {
+            day,
+            unlocked,
+            solution: fs::metadata(year_path(&format!("src/bin/{day}.rs"))).is_ok(),
+            input: FileState::of(&year_path(&format!("data/inputs/{day}.txt"))),
+            example: FileState::of(&year_path(&format!("data/examples/{day}.txt"))),
+            examples: None,
+            parts: [1, 2].map(|part| PartStatus::from_records(answers, log, day, part)),
+            total_nanos: timing.map(|t| t.total_nanos as u64),
+            timed: timings.is_day_complete(day),
+        }
+    }
+
+    pub fn stars(&self) -> usize {
+        self.parts.iter().filter(|part| part.solved).count()
+    }
+
+    fn describe_examples(&self) -> String {
+        match self.examples {
+            None => "-".into(),
+            Some((matches, total)) if matches == total => format!("✔ {total}"),
+            Some((matches, total)) => format!("✘ {matches}/{total}"),
+        }
+    }
+
+    fn describe_timing(&self) -> String {
+        match self.total_nanos {
+            Some(nanos) if self.timed => format_nanos(Some(nanos)),
+            Some(nanos) => format!("{} (partial)", format_nanos(Some(nanos))),
+            None => "-".into(),
+        }
+    }
+}
+
+/// An advent calendar of the days, with their stars. Locked days are dotted.
+pub fn render_grid(days: &[DayStatus]) -> String {
+    // NOTE: 25 days make five rows of five, 12 days two rows of six.
+    let columns = if days.len().is_multiple_of(5) { 5 } else { 6 };
+    let border = |left: &str, middle: &str, right: &str| {
+        let cells = vec!["───────"; columns.min(days.len())].join(middle);
+        format!("{left}{cells}{right}\n")
+    };
+
+    let mut out = border("┌", "┬", "┐");
+
+    for (index, row) in days.chunks(columns).enumerate() {
+        if index > 0 {
+            out.push_str(&border("├", "┼", "┤"));
+        }
+
+        out.push('│');
+        for status in row {
+            let stars = match (status.unlocked, status.stars()) {
+                (false, _) => " ·",
+                (true, 0) => "☆☆",
+                (true, 1) => "★☆",
+                (true, _) => "★★",
+            };
+            out.push_str(&format!(" {} {stars} │", status.day));
+        }
+        for _ in row.len()..columns.min(days.len()) {
+            out.push_str("       │");
+        }
+        out.push('\n');
+    }
+
+    out.push_str(&border("└", "┴", "┘"));
+    out
+}
+
+/// A table of the days that are unlocked or were worked on.
+pub fn render_table(days: &[DayStatus]) -> String {
+    let mut out = format!(
+        "{ANSI_BOLD}{:<4} {:<8} {:<6} {:<7} {:<8} {:<16} {:<16} {}{ANSI_RESET}\n",
+        "Day", "Solution", "Input", "Example", "Examples", "Part 1", "Part 2", "Time"
+    );
+
+    for status in days {
+        if !status.unlocked && !status.solution {
+            continue;
+        }
+
+        out.push_str(&format!(
+            "{:<4} {:<8} {:<6} {:<7} {:<8} {:<16} {:<16} {}\n",
+            status.day.to_string(),
+            if status.solution { "✔" } else { "-" },
+            status.input.symbol(),
+            status.example.symbol(),
+            status.describe_examples(),
+            status.parts[0].describe(),
+            status.parts[1].describe(),
+            status.describe_timing(),
+        ));
+    }
+
+    out
+}
+
+fn shorten(answer: &str) -> String {
+    if answer.chars().count() <= MAX_ANSWER_WIDTH {
+        return answer.into();
+    }
+
+    let prefix: String = answer.chars().take(MAX_ANSWER_WIDTH - 1).collect();
+    format!("{prefix}…")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayStatus, FileState, PartStatus, render_grid, render_table};
+    use crate::day;
+    use crate::template::Day;
+    use crate::template::answers::Answers;
+    use crate::template::submissions::{Outcome, SubmissionLog};
+
+    fn status(day: Day, unlocked: bool, solved: [bool; 2]) -> DayStatus {
+        DayStatus {
+            day,
+            unlocked,
+            solution: unlocked,
+            input: FileState::Present,
+            example: FileState::Empty,
+            examples: Some((1, 2)),
+            parts: solved.map(|solved| PartStatus {
+                answer: solved.then(|| "142".into()),
+                solved,
+                wrong: 0,
+            }),
+            total_nanos: Some(1_500_000),
+            timed: true,
+        }
+    }
+
+    #[test]
+    fn combines_answers_and_submissions() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, None, "142");
+
+        let mut log = SubmissionLog::default();
+        log.record(day!(1), 2, "10", Outcome::Wrong, None, None);
+        log.record(day!(1), 2, "12", Outcome::Correct, None, None);
+        log.record(day!(2), 1, "3", Outcome::Wrong, None, None);
+        log.record(day!(2), 1, "4", Outcome::Wrong, None, None);
+        log.record(day!(3), 1, "4", Outcome::AlreadySolved, None, None);
+
+        let part = |day: Day, part: u8| PartStatus::from_records(&answers, &log, day, part);
+
+        assert_eq!(part(day!(1), 1).describe(), "★ 142");
+        assert_eq!(part(day!(1), 2).describe(), "★ 12");
+        assert_eq!(part(day!(1), 2).wrong, 1);
+        assert_eq!(part(day!(2), 1).describe(), "✘ 2 wrong");
+        assert_eq!(part(day!(3), 1).describe(), "★");
+        assert_eq!(part(day!(4), 1).describe(), "-");
+    }
+
+    #[test]
+    fn renders_a_calendar() {
+        let days: Vec<DayStatus> = (1..=12)
+            .filter_map(Day::new)
+            .map(|day| {
+                let n = day.into_inner();
+                status(day, n <= 3, [n <= 2, n == 1])
+            })
+            .collect();
+
+        assert_eq!(
+            render_grid(&days),
+            "┌───────┬───────┬───────┬───────┬───────┬───────┐\n\
+             │ 01 ★★ │ 02 ★☆ │ 03 ☆☆ │ 04  · │ 05  · │ 06  · │\n\
+             ├───────┼───────┼───────┼───────┼───────┼───────┤\n\
+             │ 07  · │ 08  · │ 09  · │ 10  · │ 11  · │ 12  · │\n\
+             └───────┴───────┴───────┴───────┴───────┴───────┘\n"
+        );
+    }
+
+    #[test]
+    fn lists_unlocked_days() {
+        let days = [
+            status(day!(1), true, [true, false]),
+            status(day!(2), false, [false, false]),
+        ];
+        let table = render_table(&days);
+
+        assert_eq!(table.lines().count(), 2);
+        assert!(table.contains(
+            "01   ✔        ✔      empty   ✘ 1/2    ★ 142            -                1.5ms"
+        ));
+    }
+}
@@ -0,0 +1,646 @@
+//! Local log of submitted answers, stored in `data/submissions.json`.
+//!
+//! Before an answer is submitted, it is checked against the log: answers that were already rejected,
+//! and numeric answers outside the bounds learned from "too high" / "too low" hints, are never sent again.
+//! The log also keeps track of the cooldown imposed after a wrong or rate-limited answer.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs, io,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, year_path};
+
+fn submissions_file_path() -> String {
+    year_path("data/submissions.json")
+}
+
+/// The verdict on a submission, as recorded in the log.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    /// The part was already solved, the answer was not checked.
+    AlreadySolved,
+    /// The answer was submitted during a cooldown and was not checked. Only recorded for its wait time.
+    RateLimited,
+}
+
+/// Hint given along with a wrong answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub answer: String,
+    pub outcome: Outcome,
+    pub hint: Option<Hint>,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Seconds to wait before the next submission.
+    pub wait: Option<u64>,
+}
+
+/// The parsed response to a submission.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        /// Cooldown before the next answer can be submitted.
+        wait: Option<Duration>,
+    },
+    AlreadySolved,
+    /// The answer was submitted too soon after a previous one and was not checked.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// A response that could not be recognized, holds its text.
+    Unknown(String),
+}
+
+impl SubmitOutcome {
+    /// Parse the response to a submission. Works on the text of both adventofcode.com and aoc-cli.
+    pub fn parse(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            return SubmitOutcome::Correct;
+        }
+
+        if response.contains("That's not the right answer") {
+            let hint = if response.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if response.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+
+            return SubmitOutcome::Wrong {
+                hint,
+                wait: parse_wait(response),
+            };
+        }
+
+        if response.contains("You gave an answer too recently") {
+            return SubmitOutcome::RateLimited {
+                wait: parse_wait(response),
+            };
+        }
+
+        if response.contains("Did you already complete it?") {
+            return SubmitOutcome::AlreadySolved;
+        }
+
+        SubmitOutcome::Unknown(response.trim().to_string())
+    }
+
+    /// The outcome to record in the log. Unrecognized responses are not recorded,
+    /// rate-limited ones are, so that the cooldown they report is honoured by later submissions.
+    pub fn to_log(&self) -> Option<(Outcome, Option<Hint>, Option<Duration>)> {
+        match self {
+            SubmitOutcome::Correct => Some((Outcome::Correct, None, None)),
+            SubmitOutcome::Wrong { hint, wait } => Some((Outcome::Wrong, *hint, *wait)),
+            SubmitOutcome::AlreadySolved => Some((Outcome::AlreadySolved, None, None)),
+            SubmitOutcome::RateLimited { wait } => Some((Outcome::RateLimited, None, *wait)),
+            SubmitOutcome::Unknown(_) => None,
+        }
+    }
+}
+
+/// Extract the cooldown from phrases like "you have 1m 3s left to wait" or "please wait 5 minutes before trying again".
+fn parse_wait(response: &str) -> Option<Duration> {
+    let response = response.to_ascii_lowercase();
+
+    if let Some(end) = response.find(" left to wait") {
+        let start = response[..end].rfind("you have ")? + "you have ".len();
+
+        let seconds = response[start..end]
+            .split_whitespace()
+            .map(|token| {
+                let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+                let value: u64 = value.parse().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum::<Option<u64>>()?;
+
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let start = response.find("please wait ")? + "please wait ".len();
+    let mut tokens = response[start..].split_whitespace();
+    let value = match tokens.next()? {
+        "one" | "a" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "ten" => 10,
+        value => value.parse().ok()?,
+    };
+
+    match tokens.next()?.trim_end_matches([',', '.']) {
+        "minute" | "minutes" => Some(Duration::from_secs(value * 60)),
+        "second" | "seconds" => Some(Duration::from_secs(value)),
+        _ => None,
+    }
+}
+
+/// Format a wait time, e.g. `1m 3s`.
+pub fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs();
+    match (seconds / 60, seconds % 60) {
+        (0, s) => format!("{s}s"),
+        (m, 0) => format!("{m}m"),
+        (m, s) => format!("{m}m {s}s"),
+    }
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let wait = |wait: &Option<Duration>| {
+            wait.map(|w| format!(" Wait {} before submitting again.", format_wait(w)))
+                .unwrap_or_default()
+        };
+
+        match self {
+            SubmitOutcome::Correct => write!(f, "✔ That's the right answer!"),
+            SubmitOutcome::Wrong {
+                hint,
+                wait: cooldown,
+            } => {
+                let hint = match hint {
+                    Some(Hint::TooHigh) => ", it is too high",
+                    Some(Hint::TooLow) => ", it is too low",
+                    None => "",
+                };
+                write!(f, "✘ That's not the right answer{hint}.{}", wait(cooldown))
+            }
+            SubmitOutcome::AlreadySolved => write!(f, "This part was already solved."),
+            SubmitOutcome::RateLimited { wait: cooldown } => write!(
+                f,
+                "The answer was submitted too soon after the previous one and was not checked.{}",
+                wait(cooldown)
+            ),
+            SubmitOutcome::Unknown(response) => write!(f, "Unrecognized response:\n{response}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubmissionLog {
+    data: HashMap<(Day, u8), Vec<Submission>>,
+}
+
+impl SubmissionLog {
+    /// Read the submission log. If it is not present, nothing was submitted yet.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(submissions_file_path()) {
+            Ok(content) => {
+                let json = JsonValue::from_str(&content).map_err(|e| e.to_string())?;
+                SubmissionLog::try_from(&json)
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(SubmissionLog::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn store_file(&self) -> Result<(), io::Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(submissions_file_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// All submissions of a part, oldest first.
+    pub fn get(&self, day: Day, part: u8) -> &[Submission] {
+        self.data.get(&(day, part)).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn record(
+        &mut self,
+        day: Day,
+        part: u8,
+        answer: &str,
+        outcome: Outcome,
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    ) {
+        self.data.entry((day, part)).or_default().push(Submission {
+            answer: answer.to_string(),
+            outcome,
+            hint,
+            timestamp: now(),
+            wait: wait.map(|w| w.as_secs()),
+        });
+    }
+
+    /// Time left until the cooldown of the most recent wrong or rate-limited answer has passed, if any.
+    pub fn cooldown(&self) -> Option<Duration> {
+        let now = now();
+
+        self.data
+            .values()
+            .flatten()
+            .filter_map(|s| Some(s.timestamp + s.wait?))
+            .max()
+            .filter(|until| *until > now)
+            .map(|until| Duration::from_secs(until - now))
+    }
+
+    /// Exclusive bounds of a numeric answer, learned from previous "too low" and "too high" hints.
+    pub fn bounds(&self, day: Day, part: u8) -> (Option<i128>, Option<i128>) {
+        let hinted = |hint: Hint| {
+            self.get(day, part)
+                .iter()
+                .filter(move |s| s.hint == Some(hint))
+                .filter_map(|s| s.answer.trim().parse::<i128>().ok())
+        };
+
+        (hinted(Hint::TooLow).max(), hinted(Hint::TooHigh).min())
+    }
+
+    /// Check whether an answer may be submitted. Answers that cannot be right are refused with a reason,
+    /// answers that are merely suspicious are accepted with a list of warnings.
+    pub fn guard(&self, day: Day, part: u8, answer: &str) -> Result<Vec<String>, String> {
+        let submissions = self.get(day, part);
+
+        if let Some(correct) = submissions.iter().find(|s| s.outcome == Outcome::Correct) {
+            return Err(format!(
+                "part {part} was already solved with `{}`.",
+                correct.answer
+            ));
+        }
+
+        if submissions
+            .iter()
+            .any(|s| s.outcome == Outcome::AlreadySolved)
+        {
+            return Err(format!("part {part} was already solved."));
+        }
+
+        if submissions
+            .iter()
+            .any(|s| s.answer == answer && s.outcome == Outcome::Wrong)
+        {
+            return Err(format!("`{answer}` was already submitted and is wrong."));
+        }
+
+        if let Ok(value) = answer.trim().parse::<i128>() {
+            let (lower, upper) = self.bounds(day, part);
+
+            if let Some(lower) = lower.filter(|lower| value <= *lower) {
+                return Err(format!(
+                    "`{answer}` is too low, the answer is above {lower}."
+                ));
+            }
+
+            if let Some(upper) = upper.filter(|upper| value >= *upper) {
+                return Err(format!(
+                    "`{answer}` is too high, the answer is below {upper}."
+                ));
+            }
+        }
+
+        let mut warnings = vec![];
+
+        if answer.trim().is_empty() {
+            warnings.push("the answer is empty.".into());
+        } else if answer.trim() == "0" {
+            warnings.push("the answer is 0.".into());
+        }
+
+        if answer.contains('\n') {
+            warnings.push("the answer spans multiple lines.".into());
+        }
+
+        Ok(warnings)
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Outcome::Correct => "correct",
+            Outcome::Wrong => "wrong",
+            Outcome::AlreadySolved => "already_solved",
+            Outcome::RateLimited => "rate_limited",
+        })
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "wrong" => Ok(Outcome::Wrong),
+            "already_solved" => Ok(Outcome::AlreadySolved),
+            "rate_limited" => Ok(Outcome::RateLimited),
+            s => Err(format!("Unknown outcome `{s}`.")),
+        }
+    }
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Hint::TooHigh => "too_high",
+            Hint::TooLow => "too_low",
+        })
+    }
+}
+
+impl FromStr for Hint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too_high" => Ok(Hint::TooHigh),
+            "too_low" => Ok(Hint::TooLow),
+            s => Err(format!("Unknown hint `{s}`.")),
+        }
+    }
+}
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(value.outcome.to_string()),
+        );
+        map.insert(
+            "hint".into(),
+            value
+                .hint
+                .map_or(JsonValue::Null, |h| JsonValue::String(h.to_string())),
+        );
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "wait".into(),
+            value
+                .wait
+                .map_or(JsonValue::Null, |w| JsonValue::Number(w as f64)),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let outcome = json
+            .get("outcome")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.outcome to be a string.")?
+            .parse()?;
+
+        let hint = match json.get("hint").and_then(|v| v.get::<String>()) {
+            Some(hint) => Some(hint.parse()?),
+            None => None,
+        };
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map_or(0, |x| *x as u64);
+
+        let wait = json
+            .get("wait")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64);
+
+        Ok(Submission {
+            answer,
+            outcome,
+            hint,
+            timestamp,
+            wait,
+        })
+    }
+}
+
+impl From<&SubmissionLog> for JsonValue {
+    fn from(value: &SubmissionLog) -> Self {
+        let mut days: HashMap<String, JsonValue> = HashMap::new();
+
+        for ((day, part), submissions) in &value.data {
+            let parts = days
+                .entry(day.to_string())
+                .or_insert_with(|| JsonValue::Object(HashMap::new()));
+
+            if let JsonValue::Object(parts) = parts {
+                parts.insert(
+                    format!("part_{part}"),
+                    JsonValue::Array(submissions.iter().map(JsonValue::from).collect()),
+                );
+            }
+        }
+
+        JsonValue::Object(days)
+    }
+}
+
+impl TryFrom<&JsonValue> for SubmissionLog {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submissions to be a JSON object.")?;
+
+        let mut data = HashMap::new();
+
+        for (day, parts) in json {
+            let day = Day::from_str(day).map_err(|_| format!("Invalid day `{day}`."))?;
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or(format!("Expected submissions.{day} to be an object."))?;
+
+            for part in [1, 2] {
+                let Some(submissions) = parts.get(&format!("part_{part}")) else {
+                    continue;
+                };
+
+                let submissions = submissions
+                    .get::<Vec<JsonValue>>()
+                    .ok_or(format!(
+                        "Expected submissions.{day}.part_{part} to be an array."
+                    ))?
+                    .iter()
+                    .map(Submission::try_from)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                data.insert((day, part), submissions);
+            }
+        }
+
+        Ok(SubmissionLog { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use std::time::Duration;
+
+    use super::{Hint, Outcome, SubmissionLog, SubmitOutcome};
+    use crate::day;
+
+    fn log() -> SubmissionLog {
+        let mut log = SubmissionLog::default();
+        log.record(day!(1), 1, "100", Outcome::Wrong, Some(Hint::TooLow), None);
+        log.record(day!(1), 1, "500", Outcome::Wrong, Some(Hint::TooHigh), None);
+        log.record(day!(1), 1, "300", Outcome::Wrong, None, None);
+        log.record(day!(1), 2, "42", Outcome::Correct, None, None);
+        log
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(
+            SubmitOutcome::parse("That's the right answer! You are one gold star closer."),
+            SubmitOutcome::Correct
+        );
+        assert_eq!(
+            SubmitOutcome::parse(
+                "That's not the right answer; your answer is too high. If you're stuck, ... Please wait one minute before trying again."
+            ),
+            SubmitOutcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            SubmitOutcome::parse(
+                "That's not the right answer. If you're stuck, ... please wait 5 minutes before trying again."
+            ),
+            SubmitOutcome::Wrong {
+                hint: None,
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        assert_eq!(
+            SubmitOutcome::parse(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 3s left to wait."
+            ),
+            SubmitOutcome::RateLimited {
+                wait: Some(Duration::from_secs(63))
+            }
+        );
+        assert_eq!(
+            SubmitOutcome::parse(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            SubmitOutcome::AlreadySolved
+        );
+        assert_eq!(
+            SubmitOutcome::parse("<html>Internal error</html>\n"),
+            SubmitOutcome::Unknown("<html>Internal error</html>".into())
+        );
+    }
+
+    #[test]
+    fn tracks_cooldowns() {
+        let mut log = SubmissionLog::default();
+        assert_eq!(log.cooldown(), None);
+
+        log.record(
+            day!(1),
+            1,
+            "1",
+            Outcome::Wrong,
+            None,
+            Some(Duration::from_secs(300)),
+        );
+        assert!(log.cooldown().is_some_and(|c| c > Duration::from_secs(290)));
+
+        assert!(log.guard(day!(1), 1, "2").is_ok());
+
+        let mut log = SubmissionLog::default();
+        let (outcome, hint, wait) = SubmitOutcome::RateLimited {
+            wait: Some(Duration::from_secs(63)),
+        }
+        .to_log()
+        .unwrap();
+        log.record(day!(1), 1, "2", outcome, hint, wait);
+
+        assert!(log.cooldown().is_some_and(|c| c > Duration::from_secs(60)));
+        assert!(log.guard(day!(1), 1, "2").is_ok());
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let log = log();
+        assert!(log.guard(day!(1), 1, "300").is_err());
+        assert!(log.guard(day!(1), 2, "43").is_err());
+        assert_eq!(log.guard(day!(2), 1, "300"), Ok(vec![]));
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let log = log();
+        assert_eq!(log.bounds(day!(1), 1), (Some(100), Some(500)));
+        assert!(log.guard(day!(1), 1, "100").is_err());
+        assert!(log.guard(day!(1), 1, "50").is_err());
+        assert!(log.guard(day!(1), 1, "500").is_err());
+        assert_eq!(log.guard(day!(1), 1, "250"), Ok(vec![]));
+    }
+
+    #[test]
+    fn warns_on_suspicious_answers() {
+        let log = SubmissionLog::default();
+        assert_eq!(log.guard(day!(1), 1, "0").unwrap().len(), 1);
+        assert_eq!(log.guard(day!(1), 1, "").unwrap().len(), 1);
+        assert_eq!(log.guard(day!(1), 1, "#..\n.#.").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let log = log();
+        let json = JsonValue::from(&log);
+        assert_eq!(SubmissionLog::try_from(&json).unwrap(), log);
+    }
+}
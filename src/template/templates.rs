@@ -0,0 +1,319 @@
+//! Templates for the module file of a day, see `cargo scaffold --template <name>`.
+//!
+//! Templates live in `templates/<name>.txt`. The template used by default is set with `AOC_TEMPLATE`
+//! in `.cargo/config.toml`, and falls back to `default`.
+//!
+//! Templates may use these variables:
+//! - `%DAY_NUMBER%` and `%DAY_PADDED%`, e.g. `5` and `05`.
+//! - `%YEAR%`, the selected year.
+//! - `%TITLE%`, the title of the puzzle, if it was downloaded.
+//! - `%EXAMPLE_ANSWER_1%` and `%EXAMPLE_ANSWER_2%`, the expected answer of each part's example as a literal,
+//!   e.g. `Some(142)` or `Some(String::from("ABC"))`, if the downloaded puzzle has one.
+//!
+//! Lines between `%IF NAME%` and `%END%` are only kept if the variable is set, those between `%IF !NAME%`
+//! and `%END%` only if it is not. `%ELSE%` starts the alternative, and sections may be nested.
+
+use std::{env, fs, io};
+
+use crate::template::backend::get_puzzle_path;
+use crate::template::examples::{expected_literal, parse_puzzle};
+use crate::template::{Day, Year};
+
+/// Environment variable that holds the name of the default template.
+pub const TEMPLATE_ENV: &str = "AOC_TEMPLATE";
+
+pub const TEMPLATES_DIR: &str = "templates";
+
+const DEFAULT_TEMPLATE: &str = "default";
+
+/// The `default` template, in case the templates directory is missing.
+const BUILTIN_TEMPLATE: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/templates/default.txt"
+));
+
+/// The name of the template to use if none is passed with `--template`.
+pub fn default_name() -> String {
+    env::var(TEMPLATE_ENV)
+        .ok()
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| DEFAULT_TEMPLATE.into())
+}
+
+/// The names of the templates in the templates directory, sorted.
+pub fn available() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let name = entry.ok()?.file_name().into_string().ok()?;
+                    name.strip_suffix(".txt").map(String::from)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    names.sort_unstable();
+    names
+}
+
+/// Read the template called `name` from the templates directory.
+pub fn load(name: &str) -> Result<String, String> {
+    match fs::read_to_string(format!("{TEMPLATES_DIR}/{name}.txt")) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound && name == DEFAULT_TEMPLATE => {
+            Ok(BUILTIN_TEMPLATE.into())
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(format!(
+            "unknown template \"{name}\", expected one of: {}",
+            available().join(", ")
+        )),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// The values of the variables a template is rendered with. Variables without a value are not set.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Variables {
+    values: Vec<(&'static str, String)>,
+}
+
+impl Variables {
+    /// The variables of a day, with the title and example answers taken from its puzzle in `data/puzzles`.
+    pub fn for_day(day: Day) -> Self {
+        let puzzle = fs::read_to_string(get_puzzle_path(day)).unwrap_or_default();
+        Self::from_puzzle(day, Year::selected(), &puzzle)
+    }
+
+    pub fn from_puzzle(day: Day, year: Option<Year>, puzzle: &str) -> Self {
+        let mut variables = Self::default();
+
+        variables.set("DAY_NUMBER", Some(day.into_inner().to_string()));
+        variables.set("DAY_PADDED", Some(day.to_string()));
+        variables.set("YEAR", year.map(|year| year.to_string()));
+        variables.set("TITLE", puzzle_title(puzzle));
+
+        for candidates in parse_puzzle(puzzle) {
+            let answer = candidates
+                .default_answer()
+                .map(|index| expected_literal(Some(&candidates.answers[index])));
+
+            match candidates.part {
+                1 => variables.set("EXAMPLE_ANSWER_1", answer),
+                2 => variables.set("EXAMPLE_ANSWER_2", answer),
+                _ => {}
+            }
+        }
+
+        variables
+    }
+
+    pub fn set(&mut self, name: &'static str, value: Option<String>) {
+        self.values.retain(|(key, _)| *key != name);
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            self.values.push((name, value));
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// The title of a puzzle description in markdown, e.g. `Trebuchet?!` for `## --- Day 1: Trebuchet?! ---`.
+pub fn puzzle_title(puzzle: &str) -> Option<String> {
+    let line = puzzle.lines().find(|line| line.contains("--- Day "))?;
+    let (_, title) = line.split_once(": ")?;
+    Some(title.trim_end().trim_end_matches("---").trim().to_string())
+}
+
+/// A conditional section that is currently open.
+struct Section {
+    /// Whether the lines of the current branch are kept, disregarding enclosing sections.
+    keep: bool,
+    in_else: bool,
+}
+
+/// Render a template: evaluate its conditional sections, then substitute its variables.
+/// Unknown `%NAME%` sequences are left as they are, e.g. a remainder in `a %b% c`.
+pub fn render(template: &str, variables: &Variables) -> Result<String, String> {
+    let mut sections: Vec<Section> = vec![];
+    let mut out = String::with_capacity(template.len());
+
+    for (index, line) in template.lines().enumerate() {
+        let directive = line
+            .trim()
+            .strip_prefix('%')
+            .and_then(|line| line.strip_suffix('%'));
+
+        match directive {
+            Some(condition) if condition.starts_with("IF ") => {
+                let name = condition["IF ".len()..].trim();
+                let keep = match name.strip_prefix('!') {
+                    Some(name) => variables.get(name).is_none(),
+                    None => variables.get(name).is_some(),
+                };
+                sections.push(Section {
+                    keep,
+                    in_else: false,
+                });
+            }
+            Some("ELSE") => match sections.last_mut() {
+                Some(section) if !section.in_else => {
+                    section.keep = !section.keep;
+                    section.in_else = true;
+                }
+                _ => return Err(format!("line {}: unexpected %ELSE%", index + 1)),
+            },
+            Some("END") => {
+                if sections.pop().is_none() {
+                    return Err(format!("line {}: unexpected %END%", index + 1));
+                }
+            }
+            _ => {
+                if sections.iter().all(|section| section.keep) {
+                    out.push_str(&substitute(line, variables));
+                    out.push('\n');
+                }
+            }
+        }
+    }
+
+    if !sections.is_empty() {
+        return Err(format!("{} unclosed %IF% section(s)", sections.len()));
+    }
+
+    Ok(out)
+}
+
+fn substitute(line: &str, variables: &Variables) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find('%') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        let value = after
+            .find('%')
+            .and_then(|end| Some((variables.get(&after[..end])?, end)));
+
+        match value {
+            Some((value, end)) => {
+                out.push_str(value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('%');
+                rest = after;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Variables, available, load, render};
+    use crate::day;
+    use crate::template::Year;
+
+    const PUZZLE: &str = "## --- Day 1: Trebuchet?! ---
+
+For example:
+
+```
+1abc2
+```
+
+Adding these together produces *`142`*.
+
+## --- Part Two ---
+
+Nothing to see here.
+";
+
+    #[test]
+    fn reads_variables_from_the_puzzle() {
+        let variables = Variables::from_puzzle(day!(1), Year::new(2023), PUZZLE);
+
+        assert_eq!(variables.get("DAY_NUMBER"), Some("1"));
+        assert_eq!(variables.get("DAY_PADDED"), Some("01"));
+        assert_eq!(variables.get("YEAR"), Some("2023"));
+        assert_eq!(variables.get("TITLE"), Some("Trebuchet?!"));
+        assert_eq!(variables.get("EXAMPLE_ANSWER_1"), Some("Some(142)"));
+        assert_eq!(variables.get("EXAMPLE_ANSWER_2"), None);
+
+        let variables = Variables::from_puzzle(day!(7), None, "");
+        assert_eq!(variables.get("YEAR"), None);
+        assert_eq!(variables.get("TITLE"), None);
+    }
+
+    #[test]
+    fn quotes_text_answers() {
+        let puzzle = PUZZLE.replace("*`142`*", "*`1,2,3`*");
+        let variables = Variables::from_puzzle(day!(1), Year::new(2023), &puzzle);
+        assert_eq!(
+            variables.get("EXAMPLE_ANSWER_1"),
+            Some(r#"Some(String::from("1,2,3"))"#)
+        );
+
+        let module = render(&load("default").unwrap(), &variables).unwrap();
+        assert!(module.contains(r#"assert_eq!(result, Some(String::from("1,2,3")));"#));
+    }
+
+    #[test]
+    fn renders_variables_and_sections() {
+        let variables = Variables::from_puzzle(day!(1), Year::new(2023), PUZZLE);
+        let template = "solution!(%DAY_NUMBER%); // %DAY_PADDED% 10 % 3 %UNKNOWN%
+%IF TITLE%
+// %TITLE%
+%IF !YEAR%
+// no year
+%END%
+%END%
+%IF EXAMPLE_ANSWER_2%
+%EXAMPLE_ANSWER_2%
+%ELSE%
+None
+%END%
+";
+
+        assert_eq!(
+            render(template, &variables).unwrap(),
+            "solution!(1); // 01 10 % 3 %UNKNOWN%\n// Trebuchet?!\nNone\n"
+        );
+    }
+
+    #[test]
+    fn rejects_unbalanced_sections() {
+        let variables = Variables::default();
+
+        assert!(render("%IF TITLE%\n", &variables).is_err());
+        assert!(render("%END%\n", &variables).is_err());
+        assert!(render("%IF TITLE%\n%ELSE%\n%ELSE%\n%END%\n", &variables).is_err());
+    }
+
+    #[test]
+    fn renders_all_templates() {
+        let templates = available();
+        assert!(templates.contains(&"default".to_string()));
+
+        for name in templates {
+            for variables in [
+                Variables::from_puzzle(day!(1), None, ""),
+                Variables::from_puzzle(day!(1), Year::new(2023), PUZZLE),
+            ] {
+                let module = render(&load(&name).unwrap(), &variables).unwrap();
+                assert!(!module.contains('%'), "{name} left a variable");
+            }
+        }
+    }
+}
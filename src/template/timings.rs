@@ -1,16 +1,31 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::alloc::AllocStats;
+use crate::template::record::{PARSE_PART, PartRecord, PartStatus};
+use crate::template::stats::BenchStats;
+use crate::template::{Day, year_path};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+fn timings_file_path() -> String {
+    year_path("data/timings.json")
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    /// Only present for solutions with a shared parse stage.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub parse_stats: Option<BenchStats>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    pub parse_alloc: Option<AllocStats>,
+    pub part_1_alloc: Option<AllocStats>,
+    pub part_2_alloc: Option<AllocStats>,
+    /// Labels of the inputs the timings were combined from. Empty if the day only has its default input.
+    pub inputs: Vec<String>,
     pub total_nanos: f64,
 }
 
@@ -21,17 +36,147 @@ pub struct Timings {
     pub data: Vec<Timing>,
 }
 
+impl Timing {
+    /// Collect the benchmark results a solution reported for its parts.
+    /// Parts that were not solved or not benched are left empty, parts that were killed are marked as such.
+    /// For days with multiple inputs, the timings of the inputs are combined according to `aggregate`.
+    pub fn from_records(day: Day, records: &[PartRecord], aggregate: InputAggregate) -> Self {
+        let records: Vec<&PartRecord> = records.iter().filter(|r| r.day == day).collect();
+
+        let mut timing = Timing {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            parse_stats: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_alloc: None,
+            part_1_alloc: None,
+            part_2_alloc: None,
+            inputs: vec![],
+            total_nanos: 0_f64,
+        };
+
+        for input in records.iter().filter_map(|r| r.input.as_ref()) {
+            if !timing.inputs.contains(input) {
+                timing.inputs.push(input.clone());
+            }
+        }
+
+        for part_number in [PARSE_PART, 1, 2] {
+            let (part, stats, alloc) = match part_number {
+                PARSE_PART => (
+                    &mut timing.parse,
+                    &mut timing.parse_stats,
+                    &mut timing.parse_alloc,
+                ),
+                1 => (
+                    &mut timing.part_1,
+                    &mut timing.part_1_stats,
+                    &mut timing.part_1_alloc,
+                ),
+                _ => (
+                    &mut timing.part_2,
+                    &mut timing.part_2_stats,
+                    &mut timing.part_2_alloc,
+                ),
+            };
+
+            let part_records = records.iter().filter(|r| r.part == part_number);
+
+            if let Some(killed) = part_records.clone().find(|r| r.status.is_killed()) {
+                *part = Some(killed.status.to_string().to_uppercase());
+                continue;
+            }
+
+            // NOTE: when parts run in separate processes, each of them reports a parse stage. Only count it once per input.
+            let mut benched: Vec<&PartRecord> = vec![];
+            for record in part_records.filter(|r| r.is_benched()) {
+                if !benched.iter().any(|r| r.input == record.input) {
+                    benched.push(record);
+                }
+            }
+
+            let Some(record) = aggregate.select(benched) else {
+                continue;
+            };
+
+            *part = Some(format!("{:.1?}", record.stats.median));
+            *stats = Some(record.stats);
+            *alloc = record.alloc;
+            timing.total_nanos += record.stats.median.as_nanos() as f64;
+        }
+
+        timing
+    }
+}
+
+/// How the timings of a day with multiple inputs are combined into a single value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputAggregate {
+    /// The slowest input.
+    #[default]
+    Worst,
+    /// The median input. For an even number of inputs, the faster of the two middle inputs.
+    Median,
+}
+
+impl InputAggregate {
+    /// Read the aggregate from the `AOC_INPUT_AGGREGATE` environment variable, defaulting to [`InputAggregate::Worst`].
+    pub fn from_env() -> Self {
+        match std::env::var("AOC_INPUT_AGGREGATE") {
+            Ok(value) => value.parse().unwrap_or_else(|e| {
+                eprintln!("{e}");
+                InputAggregate::default()
+            }),
+            Err(_) => InputAggregate::default(),
+        }
+    }
+
+    /// Select the record representing all inputs.
+    fn select(self, mut records: Vec<&PartRecord>) -> Option<&PartRecord> {
+        records.sort_by_key(|r| r.stats.median);
+        match self {
+            InputAggregate::Worst => records.last().copied(),
+            InputAggregate::Median => records.get(records.len().checked_sub(1)? / 2).copied(),
+        }
+    }
+
+    /// Description for the README, e.g. `worst case`.
+    pub fn describe(self) -> &'static str {
+        match self {
+            InputAggregate::Worst => "worst case",
+            InputAggregate::Median => "median",
+        }
+    }
+}
+
+impl FromStr for InputAggregate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "worst" => Ok(InputAggregate::Worst),
+            "median" => Ok(InputAggregate::Median),
+            s => Err(format!(
+                "Unknown input aggregate `{s}`, expected `worst` or `median`."
+            )),
+        }
+    }
+}
+
 impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(timings_file_path())?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+        fs::read_to_string(timings_file_path())
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,22 +196,32 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        // NOTE: an empty `f64` sum is `-0.0`, which would print as `-0.00ms`.
+        self.data.iter().fold(0.0, |sum, x| sum + x.total_nanos) / 1_000_000_f64
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && is_measured(&t.part_1) && is_measured(&t.part_2))
     }
 }
 
+/// Whether a part holds a measurement, rather than nothing or the marker of a killed run.
+fn is_measured(part: &Option<String>) -> bool {
+    part.as_deref().is_some_and(|p| {
+        ![PartStatus::Timeout, PartStatus::OutOfMemory]
+            .iter()
+            .any(|s| s.to_string().to_uppercase() == p)
+    })
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
@@ -133,6 +288,61 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "parse_stats".into(),
+            value
+                .parse_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_1_stats".into(),
+            value
+                .part_1_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value
+                .part_2_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        for (key, alloc) in [
+            ("parse_alloc", &value.parse_alloc),
+            ("part_1_alloc", &value.part_1_alloc),
+            ("part_2_alloc", &value.part_2_alloc),
+        ] {
+            map.insert(
+                key.into(),
+                alloc.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
+
+        map.insert(
+            "inputs".into(),
+            JsonValue::Array(
+                value
+                    .inputs
+                    .iter()
+                    .cloned()
+                    .map(JsonValue::String)
+                    .collect(),
+            ),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -166,10 +376,57 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: the parse stage, stats, allocations and inputs were added later on, treat them as optional to stay compatible with older files.
+        let parse = json.get("parse").and_then(|v| v.get::<String>()).cloned();
+
+        let parse_stats = json
+            .get("parse_stats")
+            .filter(|v| !v.is_null())
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let part_1_stats = json
+            .get("part_1_stats")
+            .filter(|v| !v.is_null())
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let part_2_stats = json
+            .get("part_2_stats")
+            .filter(|v| !v.is_null())
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let alloc = |key: &str| {
+            json.get(key)
+                .filter(|v| !v.is_null())
+                .map(AllocStats::try_from)
+                .transpose()
+        };
+
+        let inputs = json
+            .get("inputs")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .map(|inputs| {
+                inputs
+                    .iter()
+                    .filter_map(|input| input.get::<String>().cloned())
+                    .collect()
+            })
+            .unwrap_or_default();
+
         Ok(Timing {
             day,
+            parse,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            parse_stats,
+            part_1_stats,
+            part_2_stats,
+            parse_alloc: alloc("parse_alloc")?,
+            part_1_alloc: alloc("part_1_alloc")?,
+            part_2_alloc: alloc("part_2_alloc")?,
+            inputs,
             total_nanos,
         })
     }
@@ -177,6 +434,106 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&AllocStats> for JsonValue {
+    fn from(value: &AllocStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "allocations".into(),
+            JsonValue::Number(value.allocations as f64),
+        );
+        map.insert("bytes".into(), JsonValue::Number(value.bytes as f64));
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for AllocStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected allocation stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|x| x as u64)
+                .ok_or(format!("Expected allocation stats.{key} to be a number."))
+        };
+
+        Ok(AllocStats {
+            allocations: number("allocations")?,
+            bytes: number("bytes")?,
+            peak_bytes: number("peak_bytes")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let durations = [
+            ("median_nanos", value.median),
+            ("min_nanos", value.min),
+            ("max_nanos", value.max),
+            ("std_dev_nanos", value.std_dev),
+            ("p95_nanos", value.p95),
+            ("p99_nanos", value.p99),
+        ];
+
+        for (key, duration) in durations {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        Ok(BenchStats {
+            median: duration("median_nanos")?,
+            min: duration("min_nanos")?,
+            max: duration("max_nanos")?,
+            std_dev: duration("std_dev_nanos")?,
+            p95: duration("p95_nanos")?,
+            p99: duration("p99_nanos")?,
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
@@ -188,20 +545,44 @@ mod tests {
             data: vec![
                 Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    parse_stats: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_alloc: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    inputs: vec![],
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    parse_stats: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_alloc: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    inputs: vec![],
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
+                    parse: None,
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    parse_stats: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_alloc: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    inputs: vec![],
                     total_nanos: 4e+10,
                 },
             ],
@@ -210,6 +591,7 @@ mod tests {
 
     mod deserialization {
         use crate::{day, template::timings::Timings};
+        use std::time::Duration;
 
         #[test]
         fn handles_json_timings() {
@@ -223,6 +605,19 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "median_nanos": 1000000, "min_nanos": 900000, "max_nanos": 1200000, "std_dev_nanos": 50000, "p95_nanos": 1100000, "p99_nanos": 1150000, "samples": 1000, "outliers": 3 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.unwrap();
+            assert_eq!(stats.median, Duration::from_millis(1));
+            assert_eq!(stats.min, Duration::from_micros(900));
+            assert_eq!(stats.samples, 1000);
+            assert_eq!(stats.outliers, 3);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -279,13 +674,21 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    parse_stats: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_alloc: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    inputs: vec![],
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +696,21 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    parse_stats: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_alloc: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    inputs: vec![],
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -307,13 +718,229 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    parse_stats: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_alloc: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    inputs: vec![],
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod from_records {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                record::PartRecord,
+                stats::BenchStats,
+                timings::{InputAggregate, Timing},
+            },
+        };
+
+        fn benched(nanos: u64) -> BenchStats {
+            BenchStats {
+                samples: 10,
+                ..BenchStats::single(Duration::from_nanos(nanos))
+            }
+        }
+
+        #[test]
+        fn collects_benched_parts() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[
+                    PartRecord::new(day!(1), 1, Some("0".into()), benched(74)),
+                    PartRecord::new(day!(1), 2, Some("10".into()), benched(74_130_000)),
+                ],
+                InputAggregate::Worst,
+            );
+            assert_eq!(timing.total_nanos, 74_130_074_f64);
+            assert_eq!(timing.part_1.unwrap(), "74.0ns");
+            assert_eq!(timing.part_2.unwrap(), "74.1ms");
+            assert_eq!(timing.part_2_stats.unwrap().samples, 10);
+        }
+
+        #[test]
+        fn handles_answers_with_timing_patterns() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[PartRecord::new(
+                    day!(1),
+                    1,
+                    Some("@ @ @ ( ) ms (2s @ 5 samples)".into()),
+                    benched(2_000_000_000),
+                )],
+                InputAggregate::Worst,
+            );
+            assert_eq!(timing.total_nanos, 2_000_000_000_f64);
+            assert_eq!(timing.part_1.unwrap(), "2.0s");
+        }
+
+        #[test]
+        fn skips_missing_and_unbenched_parts() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[
+                    PartRecord::new(day!(1), 1, None, benched(100)),
+                    PartRecord::new(
+                        day!(1),
+                        2,
+                        Some("1".into()),
+                        BenchStats::single(Duration::from_nanos(100)),
+                    ),
+                ],
+                InputAggregate::Worst,
+            );
+            assert_eq!(timing.total_nanos, 0_f64);
+            assert_eq!(timing.part_1.is_none(), true);
+            assert_eq!(timing.part_2.is_none(), true);
+        }
+    }
+
+    mod parse_stage {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                record::PartRecord,
+                stats::BenchStats,
+                timings::{InputAggregate, Timing},
+            },
+        };
+
+        #[test]
+        fn collects_parse_stage() {
+            let stats = BenchStats {
+                samples: 10,
+                ..BenchStats::single(Duration::from_micros(5))
+            };
+            let timing = Timing::from_records(
+                day!(5),
+                &[
+                    PartRecord::parse(day!(5), stats),
+                    PartRecord::new(day!(5), 1, Some("3".into()), stats),
+                ],
+                InputAggregate::Worst,
+            );
+            assert_eq!(timing.parse.unwrap(), "5.0µs");
+            assert_eq!(timing.part_1.unwrap(), "5.0µs");
+            assert_eq!(timing.total_nanos, 10_000_f64);
+        }
+
+        #[test]
+        fn counts_repeated_parse_stage_once() {
+            let stats = BenchStats {
+                samples: 10,
+                ..BenchStats::single(Duration::from_micros(5))
+            };
+            let timing = Timing::from_records(
+                day!(5),
+                &[
+                    PartRecord::parse(day!(5), stats),
+                    PartRecord::new(day!(5), 1, Some("3".into()), stats),
+                    PartRecord::parse(day!(5), stats),
+                    PartRecord::new(day!(5), 2, Some("14".into()), stats),
+                ],
+                InputAggregate::Worst,
+            );
+            assert_eq!(timing.total_nanos, 15_000_f64);
+        }
+    }
+
+    mod inputs {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                record::PartRecord,
+                stats::BenchStats,
+                timings::{InputAggregate, Timing},
+            },
+        };
+
+        fn records() -> Vec<PartRecord> {
+            [("alice", 30), ("bob", 10), ("carol", 20)]
+                .into_iter()
+                .flat_map(|(input, micros)| {
+                    let stats = BenchStats {
+                        samples: 10,
+                        ..BenchStats::single(Duration::from_micros(micros))
+                    };
+                    [
+                        PartRecord::parse(day!(5), stats).with_input(Some(input.into())),
+                        PartRecord::parse(day!(5), stats).with_input(Some(input.into())),
+                        PartRecord::new(day!(5), 1, Some("3".into()), stats)
+                            .with_input(Some(input.into())),
+                    ]
+                })
+                .collect()
+        }
+
+        #[test]
+        fn selects_worst_input() {
+            let timing = Timing::from_records(day!(5), &records(), InputAggregate::Worst);
+            assert_eq!(timing.inputs, vec!["alice", "bob", "carol"]);
+            assert_eq!(timing.parse.as_deref(), Some("30.0µs"));
+            assert_eq!(timing.part_1.as_deref(), Some("30.0µs"));
+            assert_eq!(timing.total_nanos, 60_000_f64);
+        }
+
+        #[test]
+        fn selects_median_input() {
+            let timing = Timing::from_records(day!(5), &records(), InputAggregate::Median);
+            assert_eq!(timing.part_1.as_deref(), Some("20.0µs"));
+            assert_eq!(timing.total_nanos, 40_000_f64);
+        }
+    }
+
+    mod limits {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                record::{PartRecord, PartStatus},
+                stats::BenchStats,
+                timings::{InputAggregate, Timing, Timings},
+            },
+        };
+
+        #[test]
+        fn marks_killed_parts() {
+            let timing = Timing::from_records(
+                day!(9),
+                &[
+                    PartRecord::new(
+                        day!(9),
+                        1,
+                        Some("1".into()),
+                        BenchStats {
+                            samples: 10,
+                            ..BenchStats::single(Duration::from_micros(5))
+                        },
+                    ),
+                    PartRecord::killed(day!(9), 2, PartStatus::Timeout, Duration::from_secs(10)),
+                ],
+                InputAggregate::Worst,
+            );
+            assert_eq!(timing.part_2.as_deref(), Some("TIMEOUT"));
+            assert_eq!(timing.total_nanos, 5_000_f64);
+
+            let timings = Timings { data: vec![timing] };
+            assert_eq!(timings.is_day_complete(day!(9)), false);
         }
     }
 
@@ -331,8 +958,16 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    parse_stats: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_alloc: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    inputs: vec![],
                     total_nanos: 0_f64,
                 }],
             };
@@ -351,8 +986,16 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    parse_stats: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_alloc: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    inputs: vec![],
                     total_nanos: 0_f64,
                 }],
             };
@@ -0,0 +1,155 @@
+//! Years of the event, and the layout that keeps them apart.
+//!
+//! The active year, `AOC_YEAR` in `.cargo/config.toml`, lives at the root of the repository: its solutions
+//! in `src/bin`, its data in `data`. Past years are archived with `cargo rollover` and keep the same layout
+//! below `years/<year>`, e.g. `years/2024/src/bin/01.rs` and `years/2024/data/inputs/01.txt`.
+//!
+//! Every command takes `--year <year>` to operate on an archived year instead of the active one.
+
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::{env, sync::OnceLock};
+
+/// Environment variable that holds the active year.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// Directory that holds the archived years.
+pub const YEARS_DIR: &str = "years";
+
+static SELECTED: OnceLock<Year> = OnceLock::new();
+
+/// A year of the event, 2015 being the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] if the event took place in it, returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < 2015 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The year whose files live at the root of the repository.
+    pub fn active() -> Option<Self> {
+        env::var(YEAR_ENV).ok()?.parse().ok()
+    }
+
+    /// The year commands operate on: the one passed with `--year`, or the active year.
+    pub fn selected() -> Option<Self> {
+        SELECTED.get().copied().or_else(Self::active)
+    }
+
+    /// Select the year commands operate on. Only the first selection of a process counts.
+    pub fn select(year: Self) {
+        let _ = SELECTED.set(year);
+    }
+
+    /// Select the year passed with `--year` to this process, if any.
+    pub fn select_from_args() -> Result<(), pico_args::Error> {
+        let mut args = pico_args::Arguments::from_env();
+        if let Some(year) = args.opt_value_from_str::<_, Year>("--year")? {
+            Self::select(year);
+        }
+        Ok(())
+    }
+
+    pub fn is_active(self) -> bool {
+        Self::active().is_none_or(|active| active == self)
+    }
+
+    /// Root directory of the year's files, relative to the repository.
+    pub fn root(self) -> String {
+        if self.is_active() {
+            ".".into()
+        } else {
+            self.archive_root()
+        }
+    }
+
+    /// Root directory of the year's files once it is archived.
+    pub fn archive_root(self) -> String {
+        format!("{YEARS_DIR}/{self}")
+    }
+
+    /// A path within the year's files, e.g. `data/answers.json`.
+    pub fn path(self, path: &str) -> String {
+        if self.is_active() {
+            path.into()
+        } else {
+            format!("{}/{path}", self.archive_root())
+        }
+    }
+}
+
+/// A path within the files of the selected year, see [`Year::path`].
+pub fn year_path(path: &str) -> String {
+    match Year::selected() {
+        Some(year) => year.path(path),
+        None => path.into(),
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of the event, 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().unwrap(), Year::new(2024).unwrap());
+        assert!("2014".parse::<Year>().is_err());
+        assert!("next".parse::<Year>().is_err());
+        assert_eq!(Year::new(2016).unwrap().to_string(), "2016");
+    }
+
+    #[test]
+    fn scopes_paths_of_archived_years() {
+        // NOTE: the active year is set to 2025 in `.cargo/config.toml`.
+        let active = Year::new(2025).unwrap();
+        let archived = Year::new(2019).unwrap();
+
+        assert_eq!(active.path("data/answers.json"), "data/answers.json");
+        assert_eq!(archived.root(), "years/2019");
+        assert_eq!(
+            archived.path("data/answers.json"),
+            "years/2019/data/answers.json"
+        );
+    }
+}